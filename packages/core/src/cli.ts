@@ -200,6 +200,28 @@ function normalizeLineEndings(content: string): string {
   return content.replace(/\r\n/g, '\n').replace(/\r/g, '\n');
 }
 
+/**
+ * Read a cell piped on stdin.
+ * A UTF-8 BOM and the final newline (added by echo or an editor) are not part
+ * of the cell, so they are stripped before formatting. Piped input then formats
+ * the same as the equivalent -i input. `original` is the input as given, minus
+ * only the final newline, for check to compare the output bytes against.
+ */
+async function readStdinCell(ctx: CliContext): Promise<{
+  content: string;
+  original: string;
+  hadTrailingNewline: boolean;
+}> {
+  const stdin = await ctx.readStdin();
+  const original = stdin.endsWith('\n') ? stdin.slice(0, -1) : stdin;
+  const raw = normalizeLineEndings(stdin);
+  const withoutBom = raw.startsWith('\uFEFF') ? raw.slice(1) : raw;
+  const content = withoutBom.endsWith('\n')
+    ? withoutBom.slice(0, -1)
+    : withoutBom;
+  return { content, original, hadTrailingNewline: content !== withoutBom };
+}
+
 /** Print main help */
 function printHelp(ctx: CliContext) {
   ctx.stdout(`fabfmt - Fabric Notebook Formatter (Spark SQL & Python)
@@ -388,7 +410,7 @@ async function cmdFormat(args: string[], ctx: CliContext): Promise<void> {
       await initializePythonFormatter();
     }
 
-    const content = await ctx.readStdin();
+    const { content, hadTrailingNewline } = await readStdinCell(ctx);
     const result = formatCell(content, type);
 
     if (result.error) {
@@ -396,7 +418,9 @@ async function cmdFormat(args: string[], ctx: CliContext): Promise<void> {
       ctx.exit(1);
     }
 
-    ctx.stdout(result.formatted);
+    // Give back the final newline so the output still ends its last line
+    const needsNewline = hadTrailingNewline && !result.formatted.endsWith('\n');
+    ctx.stdout(needsNewline ? `${result.formatted}\n` : result.formatted);
     return;
   }
 
@@ -493,7 +517,9 @@ async function cmdFormat(args: string[], ctx: CliContext): Promise<void> {
   await initializeFormatters(languagesNeeded);
 
   // Format files in-place
+  // A file that fails to format is reported and skipped; the rest still run
   let formattedCount = 0;
+  let failedCount = 0;
   for (const file of files) {
     const normalizedContent = fileContents.get(file);
     if (!normalizedContent) continue; // Should never happen
@@ -503,8 +529,8 @@ async function cmdFormat(args: string[], ctx: CliContext): Promise<void> {
     } catch (e: unknown) {
       const error = e as Error;
       ctx.stderr(`Error formatting ${file}: ${error.message}`);
-      ctx.exit(1);
-      return; // TypeScript: unreachable but makes control flow analysis happy
+      failedCount++;
+      continue;
     }
 
     if (formatted !== normalizedContent) {
@@ -528,7 +554,12 @@ async function cmdFormat(args: string[], ctx: CliContext): Promise<void> {
     }
   }
 
-  if (formattedCount === 0) {
+  if (failedCount > 0) {
+    ctx.stdout(
+      `Formatted ${formattedCount} of ${files.length} file(s), ${failedCount} failed\n`,
+    );
+    ctx.exit(1);
+  } else if (formattedCount === 0) {
     ctx.stdout(`All ${files.length} file(s) already formatted\n`);
   } else {
     ctx.stdout(`Formatted ${formattedCount} of ${files.length} file(s)\n`);
@@ -579,7 +610,7 @@ async function cmdCheck(args: string[], ctx: CliContext): Promise<void> {
       await initializePythonFormatter();
     }
 
-    const content = await ctx.readStdin();
+    const { content, original } = await readStdinCell(ctx);
     const result = formatCell(content, type);
 
     if (result.error) {
@@ -587,8 +618,8 @@ async function cmdCheck(args: string[], ctx: CliContext): Promise<void> {
       ctx.exit(1);
     }
 
-    // Exit 1 if formatting would change the input
-    if (result.formatted !== content) {
+    // Exit 1 if formatting would change the input, BOM and CRLF included
+    if (result.formatted !== original) {
       ctx.exit(1);
     }
     return;
@@ -641,7 +672,9 @@ async function cmdCheck(args: string[], ctx: CliContext): Promise<void> {
   await initializeFormatters(languagesNeeded);
 
   // Check mode: check without modifying
+  // A file that fails to format is reported and skipped; the rest still run
   let needsFormatting = false;
+  let failedCount = 0;
   for (const file of files) {
     const content = fileContents.get(file);
     if (!content) continue; // Should never happen
@@ -651,8 +684,8 @@ async function cmdCheck(args: string[], ctx: CliContext): Promise<void> {
     } catch (e: unknown) {
       const error = e as Error;
       ctx.stderr(`Error checking ${file}: ${error.message}`);
-      ctx.exit(1);
-      return; // TypeScript: unreachable but makes control flow analysis happy
+      failedCount++;
+      continue;
     }
 
     if (formatted !== content) {
@@ -661,6 +694,12 @@ async function cmdCheck(args: string[], ctx: CliContext): Promise<void> {
     }
   }
 
+  if (failedCount > 0) {
+    ctx.stdout(
+      `Checked ${files.length - failedCount} of ${files.length} file(s), ${failedCount} failed\n`,
+    );
+    ctx.exit(1);
+  }
   if (needsFormatting) {
     ctx.exit(1);
   }
@@ -4,6 +4,8 @@
  * Supports two types of format directives:
  * 1. Statement-level: "-- fmt: off" or block comment at start of statement
  *    - Bypasses all formatting for the entire statement
+ *    - A later statement starting with "-- fmt: on" extends the bypass to
+ *      every statement in between
  * 2. Line-level inline: "-- fmt: inline" or block comment version
  *    - Suppresses multi-line expansion while keeping other formatting
  */
@@ -33,6 +35,13 @@ export interface FormatDirectiveInfo {
 const STATEMENT_OFF_PATTERN =
   /^\s*(?:--\s*fmt\s*:\s*off\s*$|--\s*fmt\s*:\s*off\s+|\/\*\s*fmt\s*:\s*off\s*\*\/)/i;
 
+/**
+ * Pattern to detect fmt:on at the start of a statement, ending a fmt:off region.
+ * Matches: "-- fmt: on" or "-- fmt:on" or block comment version (case-insensitive)
+ */
+const STATEMENT_ON_PATTERN =
+  /^\s*(?:--\s*fmt\s*:\s*on\s*$|--\s*fmt\s*:\s*on\s+|\/\*\s*fmt\s*:\s*on\s*\*\/)/i;
+
 /**
  * Pattern to detect line-level fmt:inline anywhere on a line.
  * Matches: "-- fmt: inline" or "-- fmt:inline" or block comment version (case-insensitive)
@@ -54,6 +63,36 @@ export function hasFormatOff(statement: string): boolean {
   return STATEMENT_OFF_PATTERN.test(statement);
 }
 
+/**
+ * Find the statements that bypass formatting.
+ * A statement starting with fmt:off is always left as written. If a later
+ * statement starts with fmt:on, every statement before it is left as written
+ * too, so a region spanning several statements can be fenced off.
+ *
+ * @param statements - The statements of one cell, in order
+ * @returns Indices of the statements to leave unformatted
+ */
+export function findFormatOffStatements(statements: string[]): Set<number> {
+  const skipped = new Set<number>();
+
+  for (let i = 0; i < statements.length; i++) {
+    if (!hasFormatOff(statements[i])) continue;
+    skipped.add(i);
+
+    // Without a matching fmt:on, fmt:off covers only its own statement
+    const end = statements.findIndex(
+      (stmt, j) => j > i && STATEMENT_ON_PATTERN.test(stmt),
+    );
+    if (end === -1) continue;
+    for (let j = i + 1; j < end; j++) {
+      skipped.add(j);
+    }
+    i = end - 1;
+  }
+
+  return skipped;
+}
+
 /**
  * Detect all fmt:inline directives in a SQL string.
  *
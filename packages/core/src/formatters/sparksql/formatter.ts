@@ -15,11 +15,12 @@
  */
 
 import antlr4 from 'antlr4';
+import type { FormatResult } from '../types.js';
 import { MAX_LINE_WIDTH } from './constants.js';
 import {
   detectCollapseDirectives,
   type FormatDirectiveInfo,
-  hasFormatOff,
+  findFormatOffStatements,
   isFmtInlineComment,
 } from './fmt-detector.js';
 import {
@@ -54,7 +55,12 @@ import {
   isKeywordToken,
   SqlBaseLexer,
 } from './token-utils.js';
-import type { AnalyzerResult, ExpandedPivot, ExpandedWindow } from './types.js';
+import type {
+  AnalyzerResult,
+  ExpandedPivot,
+  ExpandedWindow,
+  StatementSpan,
+} from './types.js';
 
 // ============================================================================
 // MODULE-LEVEL CONSTANTS (avoid allocation in hot paths)
@@ -164,6 +170,27 @@ function releaseParserInstance(instance: ParserInstance): void {
   instance.inUse = false;
 }
 
+/**
+ * Locate the %%sql magic command (%sql is not valid in Fabric).
+ * Only the text after it is SQL; anything before it (e.g. Python code) is
+ * left untouched. Without a magic command the whole input is SQL.
+ */
+function findMagicCommand(sql: string): {
+  prefix: string;
+  magicCommand: string;
+  sqlStart: number;
+} {
+  const magicMatch = sql.match(/(%%sql)[ \t]*\n?/);
+  if (!magicMatch || magicMatch.index === undefined) {
+    return { prefix: '', magicCommand: '', sqlStart: 0 };
+  }
+  return {
+    prefix: sql.substring(0, magicMatch.index),
+    magicCommand: magicMatch[1],
+    sqlStart: magicMatch.index + magicMatch[0].length,
+  };
+}
+
 // ============================================================================
 // PUBLIC API
 // ============================================================================
@@ -174,29 +201,20 @@ function releaseParserInstance(instance: ParserInstance): void {
  */
 export function formatSql(sql: string): string {
   try {
-    // Handle magic commands (%%sql only - %sql is not valid in Fabric)
-    // Find magic command anywhere in input - only format SQL after it
-    // This allows content before the magic (e.g., Python code) to remain untouched
-    let prefix = '';
-    let magicCommand = '';
-    let sqlToFormat = sql;
-
-    const magicMatch = sql.match(/(%%sql)[ \t]*\n?/);
-    if (magicMatch && magicMatch.index !== undefined) {
-      prefix = sql.substring(0, magicMatch.index);
-      magicCommand = magicMatch[1];
-      sqlToFormat = sql.substring(magicMatch.index + magicMatch[0].length);
-    }
+    // Only format SQL after the magic command
+    const { prefix, magicCommand, sqlStart } = findMagicCommand(sql);
+    const sqlToFormat = sql.substring(sqlStart);
 
     // Split on semicolons and format each statement
     const statements = splitOnSemicolons(sqlToFormat);
     const formattedStatements: string[] = [];
+    const formatOff = findFormatOffStatements(statements);
 
-    for (const stmt of statements) {
+    for (const [index, stmt] of statements.entries()) {
       if (stmt.trim().length === 0) continue;
 
       // Check for statement-level fmt:off (bypass formatting entirely)
-      if (hasFormatOff(stmt.trim())) {
+      if (formatOff.has(index)) {
         formattedStatements.push(stmt.trim());
         continue;
       }
@@ -207,7 +225,8 @@ export function formatSql(sql: string): string {
 
     let result = formattedStatements.join(';\n\n');
 
-    // Preserve trailing semicolon if original had one
+    // Preserve trailing semicolon if original had one, even if every
+    // statement was empty (';;;' formats to ';')
     if (sqlToFormat.trimEnd().endsWith(';')) {
       result += ';';
     }
@@ -223,11 +242,78 @@ export function formatSql(sql: string): string {
   }
 }
 
+/**
+ * Format SQL and report whether the output differs from the input.
+ * Saves batch tooling from comparing the strings a second time.
+ */
+export function formatSqlChecked(sql: string): FormatResult {
+  const formatted = formatSql(sql);
+  return { formatted, changed: formatted !== sql };
+}
+
 /**
  * Check if SQL needs formatting.
  */
 export function needsFormatting(sql: string): boolean {
-  return formatSql(sql) !== sql;
+  return formatSqlChecked(sql).changed;
+}
+
+/**
+ * Get the source location of each statement in the input.
+ * Used by editor integrations to map statements back to the original text.
+ */
+export function getStatementSpans(sql: string): StatementSpan[] {
+  // Statements start after the %%sql magic command, same as formatSql
+  const offset = findMagicCommand(sql).sqlStart;
+
+  const segments = splitOnSemicolonsWithOffsets(sql.substring(offset));
+  return segments.map((segment) => {
+    const leading = segment.text.length - segment.text.trimStart().length;
+    const text = segment.text.trim();
+    const start = offset + segment.start + leading;
+    return { start, end: start + text.length, text };
+  });
+}
+
+/**
+ * Format only the statements overlapping a character range.
+ * Returns the full input with just those statements reformatted; everything
+ * else (other statements, separators, whitespace) is left untouched.
+ * A range that partially overlaps a statement formats that whole statement.
+ * An empty range (start === end) formats the statement containing it.
+ */
+export function formatSqlRange(
+  sql: string,
+  start: number,
+  end: number,
+): string {
+  try {
+    const overlaps = (span: StatementSpan): boolean => {
+      if (start === end) return span.start <= start && start <= span.end;
+      return span.start < end && start < span.end;
+    };
+    const allSpans = getStatementSpans(sql);
+    const formatOff = findFormatOffStatements(
+      allSpans.map((span) => span.text),
+    );
+    const spans = allSpans.filter(overlaps);
+
+    // Replace from the end so earlier offsets stay valid
+    let result = sql;
+    for (let i = spans.length - 1; i >= 0; i--) {
+      const span = spans[i];
+      const formatted = formatOff.has(allSpans.indexOf(span))
+        ? span.text
+        : formatSingleStatement(span.text);
+      result =
+        result.substring(0, span.start) +
+        formatted +
+        result.substring(span.end);
+    }
+    return result;
+  } catch {
+    return sql;
+  }
 }
 
 // ============================================================================
@@ -238,8 +324,19 @@ export function needsFormatting(sql: string): boolean {
  * Split SQL on semicolons, but not semicolons inside string literals.
  */
 function splitOnSemicolons(sql: string): string[] {
-  const statements: string[] = [];
+  return splitOnSemicolonsWithOffsets(sql).map((segment) => segment.text);
+}
+
+/**
+ * Split SQL on semicolons, keeping the offset where each statement starts.
+ * Empty statements (only whitespace between semicolons) are dropped.
+ */
+function splitOnSemicolonsWithOffsets(
+  sql: string,
+): { text: string; start: number }[] {
+  const statements: { text: string; start: number }[] = [];
   let current = '';
+  let currentStart = 0;
   let inSingleQuote = false;
   let inDoubleQuote = false;
   let escaped = false;
@@ -267,16 +364,17 @@ function splitOnSemicolons(sql: string): string[] {
       current += ch;
     } else if (ch === ';' && !inSingleQuote && !inDoubleQuote) {
       if (current.trim().length > 0) {
-        statements.push(current);
+        statements.push({ text: current, start: currentStart });
       }
       current = '';
+      currentStart = i + 1;
     } else {
       current += ch;
     }
   }
 
   if (current.trim().length > 0) {
-    statements.push(current);
+    statements.push({ text: current, start: currentStart });
   }
 
   return statements;
@@ -301,7 +399,7 @@ interface VariableSubstitution {
  * Replace ${variable} patterns with safe placeholders before formatting.
  * Returns the modified SQL and a map to restore later.
  */
-function extractVariables(sql: string): {
+export function extractVariables(sql: string): {
   sql: string;
   substitutions: VariableSubstitution[];
 } {
@@ -344,19 +442,74 @@ function restoreVariables(
  * - Scientific notation: 1.23e10 (lowercase 'e') vs 1.23E10 (uppercase 'E')
  *
  * We normalize these to uppercase before lexing so both streams align.
+ * String literals, quoted identifiers, and comments are passed through
+ * verbatim, including any newlines and indentation inside them.
  */
 function normalizeForTokenization(sql: string): string {
   // Normalize scientific notation: replace lowercase 'e' in numbers with uppercase 'E'
   // Pattern matches: integer part (optional decimal), 'e', optional +/-, exponent
   // Examples: 1e10, 1.23e10, .5e-3, 1.e+5
+  // The first alternative matches quoted text and comments so they are skipped
+  // Digits inside a word (0x1e5, c1e2) are part of an identifier, not a number
   return sql.replace(
-    /(\d+(?:\.\d*)?|\.\d+)e([+-]?\d+)/gi,
-    (_match, mantissa, exponent) => {
+    /('(?:[^'\\]|\\.)*'|"(?:[^"\\]|\\.)*"|`[^`]*`|--[^\n]*|\/\*[\s\S]*?\*\/)|(?<!\w)(\d+(?:\.\d*)?|\.\d+)e([+-]?\d+)/gi,
+    (match, verbatim, mantissa, exponent) => {
+      if (verbatim) return match;
       return `${mantissa}E${exponent}`;
     },
   );
 }
 
+/**
+ * Check for a line comment between two token indices (exclusive).
+ * A line comment inside a function call ends its line, so the call
+ * is expanded to keep each comment with its argument.
+ */
+function hasLineCommentBetween(
+  tokens: any[],
+  startIdx: number,
+  endIdx: number,
+): boolean {
+  for (let j = startIdx + 1; j < endIdx && j < tokens.length; j++) {
+    if (tokens[j].type === SqlBaseLexer.SIMPLE_COMMENT) return true;
+  }
+  return false;
+}
+
+/**
+ * Check for a trailing comma before FROM, a closing paren, or end of statement.
+ * Examples: SELECT a, b, FROM t / GROUP BY a, / func(a, b,)
+ *
+ * Spark SQL rejects these, and the parser's error recovery would reflow the
+ * surrounding list unpredictably, so such statements are left untouched.
+ */
+function hasTrailingComma(tokens: any[]): boolean {
+  for (let i = 0; i < tokens.length; i++) {
+    if (tokens[i].type !== SqlBaseLexer.COMMA) continue;
+
+    let j = i + 1;
+    while (
+      j < tokens.length &&
+      (tokens[j].type === SqlBaseLexer.WS ||
+        tokens[j].type === SqlBaseLexer.SIMPLE_COMMENT ||
+        tokens[j].type === SqlBaseLexer.BRACKETED_COMMENT)
+    ) {
+      j++;
+    }
+
+    const next = tokens[j];
+    if (
+      !next ||
+      next.type === antlr4.Token.EOF ||
+      next.type === SqlBaseLexer.RIGHT_PAREN ||
+      next.type === SqlBaseLexer.FROM
+    ) {
+      return true;
+    }
+  }
+  return false;
+}
+
 /**
  * Format a single SQL statement.
  *
@@ -391,6 +544,10 @@ function formatSingleStatement(sql: string): string {
     tokenStream.fetchedEOF = false;
     instance.tokens.fill();
 
+    if (hasTrailingComma(instance.tokens.tokens)) {
+      return sql;
+    }
+
     // Reset parser
     instance.parser.reset();
 
@@ -1004,7 +1161,12 @@ function formatTokens(
     if (
       multiArgFuncInfo &&
       !forceCollapse &&
-      shouldExpandFunction(builder.getColumn(), multiArgFuncInfo)
+      (shouldExpandFunction(builder.getColumn(), multiArgFuncInfo) ||
+        hasLineCommentBetween(
+          tokens,
+          tokenIndex,
+          multiArgFuncInfo.closeParenIndex,
+        ))
     ) {
       handleFunctionExpansion(
         builder,
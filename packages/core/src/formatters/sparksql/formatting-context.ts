@@ -387,8 +387,8 @@ export class IndentCalculator {
 }
 
 /**
- * Determines if a +/-/~ operator is unary based on previous token.
- * Tilde (~) is always unary (bitwise NOT), while +/- depend on context.
+ * Determines if a +/-/~/! operator is unary based on previous token.
+ * Tilde (~) is always unary (bitwise NOT), while +/-/! depend on context.
  */
 
 // Module-level Set to avoid allocating on every isUnaryOperator call
@@ -419,6 +419,8 @@ const EXPECTS_EXPRESSION = new Set([
   'DIV',
   'AS',
   'SET',
+  'INTERVAL',
+  'NOT',
 ]);
 
 export function isUnaryOperator(
@@ -429,14 +431,18 @@ export function isUnaryOperator(
   // Tilde is always unary (bitwise NOT)
   if (text === '~') return true;
 
-  if (text !== '+' && text !== '-') return false;
+  // Bang is logical NOT only in prefix position; `a ! IN (...)` is binary
+  if (text !== '+' && text !== '-' && text !== '!') return false;
 
   // Check if previous token indicates unary context
   if (
     prevTokenText === '' ||
     prevTokenText === '(' ||
     prevTokenText === '[' ||
-    prevTokenText === ','
+    prevTokenText === ',' ||
+    // Stacked prefix operators: !!a, ~-a
+    prevTokenText === '!' ||
+    prevTokenText === '~'
   ) {
     return true;
   }
@@ -10,6 +10,7 @@ import {
   indentCalc,
 } from './formatting-context.js';
 import type { OutputBuilder } from './output-builder.js';
+import { getTokenType } from './token-utils.js';
 import type { AnalyzerResult, MultiArgFunctionInfo } from './types.js';
 
 /**
@@ -44,7 +45,14 @@ export function handleFunctionExpansion(
   const shouldConsiderChaining = depth % 2 === 1;
 
   if (shouldConsiderChaining) {
-    const nextTokenIdx = findNextNonWsTokenIndex(currentIndex + 1);
+    // Qualified names (db.func) are marked on their last segment, so skip
+    // ahead to the token directly before the nested paren
+    let nextTokenIdx = findNextNonWsTokenIndex(currentIndex + 1);
+    while (nextTokenIdx > 0) {
+      const dotIdx = findNextNonWsTokenIndex(nextTokenIdx + 1);
+      if (dotIdx < 0 || tokenList[dotIdx].type !== getTokenType('DOT')) break;
+      nextTokenIdx = findNextNonWsTokenIndex(dotIdx + 1);
+    }
     if (nextTokenIdx > 0 && nextTokenIdx < tokenList.length) {
       const nextToken = tokenList[nextTokenIdx];
       const isNextTokenFuncName = analysis.functionCallTokens.has(
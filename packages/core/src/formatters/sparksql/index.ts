@@ -9,7 +9,25 @@
 // PUBLIC API
 // ============================================================================
 
-export { formatSql, needsFormatting } from './formatter.js';
+export {
+  formatSql,
+  formatSqlChecked,
+  formatSqlRange,
+  getStatementSpans,
+  needsFormatting,
+} from './formatter.js';
+
+// ============================================================================
+// TOKENIZER
+// ============================================================================
+
+export { tokenize } from './tokenizer.js';
+
+// ============================================================================
+// QUERY METRICS
+// ============================================================================
+
+export { analyzeSql } from './metrics.js';
 
 // ============================================================================
 // FORMATTER CLASS (LanguageFormatter interface)
@@ -50,7 +68,11 @@ export type {
   NestedFunctionInfo,
   PendingComment,
   PivotInfo,
+  QueryMetrics,
   SimpleQueryInfo,
+  SqlToken,
+  SqlTokenKind,
+  StatementSpan,
   TokenContext,
   WindowDefInfo,
 } from './types.js';
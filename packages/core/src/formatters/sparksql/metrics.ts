@@ -0,0 +1,162 @@
+/**
+ * Query Metrics - Structural Complexity Report
+ *
+ * Parses each statement with the Spark SQL grammar and counts the
+ * constructs that make a query harder to read: explicit joins, subqueries,
+ * CTEs, nesting depth, and SELECT *.
+ *
+ * Counts are structural and grammar-driven, consistent with the formatter's
+ * own subquery detection in ParseTreeAnalyzer.
+ */
+
+import antlr4, { ErrorListener } from 'antlr4';
+import { extractVariables, getStatementSpans } from './formatter.js';
+import SqlBaseLexer from './generated/SqlBaseLexer.js';
+import SqlBaseParser from './generated/SqlBaseParser.js';
+import SqlBaseParserVisitor from './generated/SqlBaseParserVisitor.js';
+import type { QueryMetrics } from './types.js';
+
+/**
+ * Counts lexer and parser errors so a statement that only parsed through
+ * error recovery can be left out of the report.
+ */
+class ErrorCounter extends ErrorListener<any> {
+  errors = 0;
+
+  syntaxError(): void {
+    this.errors++;
+  }
+}
+
+/**
+ * Visitor that accumulates metrics across one or more parse trees.
+ */
+class MetricsVisitor extends SqlBaseParserVisitor {
+  joins = 0;
+  subqueries = 0;
+  ctes = 0;
+  maxNestingDepth = 0;
+  selectStar = false;
+
+  private depth = 0;
+  private functionCallDepth = 0;
+
+  visit(ctx: any): any {
+    if (!ctx) return null;
+    return this.visitChildren(ctx);
+  }
+
+  visitChildren(ctx: any): any {
+    if (!ctx?.children) return null;
+    for (const child of ctx.children) {
+      if (child?.accept) child.accept(this);
+    }
+    return null;
+  }
+
+  visitJoinRelation(ctx: any): any {
+    this.joins++;
+    return this.visitChildren(ctx);
+  }
+
+  visitNamedQuery(ctx: any): any {
+    // CTE body counts as a nesting level, same as the formatter's indent
+    this.ctes++;
+    return this._visitNested(ctx);
+  }
+
+  visitAliasedQuery(ctx: any): any {
+    this.subqueries++;
+    return this._visitNested(ctx);
+  }
+
+  visitExists(ctx: any): any {
+    this.subqueries++;
+    return this._visitNested(ctx);
+  }
+
+  visitSubqueryExpression(ctx: any): any {
+    this.subqueries++;
+    return this._visitNested(ctx);
+  }
+
+  visitPredicate(ctx: any): any {
+    // IN (SELECT ...) - the query is a direct child of the predicate
+    const hasQuery = ctx.children?.some(
+      (child: any) =>
+        child.ruleIndex !== undefined &&
+        SqlBaseParser.ruleNames[child.ruleIndex] === 'query',
+    );
+    if (hasQuery) {
+      this.subqueries++;
+      return this._visitNested(ctx);
+    }
+    return this.visitChildren(ctx);
+  }
+
+  visitFunctionCall(ctx: any): any {
+    // COUNT(*) is not a SELECT *
+    this.functionCallDepth++;
+    const result = this.visitChildren(ctx);
+    this.functionCallDepth--;
+    return result;
+  }
+
+  visitStar(ctx: any): any {
+    if (this.functionCallDepth === 0) this.selectStar = true;
+    return this.visitChildren(ctx);
+  }
+
+  private _visitNested(ctx: any): any {
+    this.depth++;
+    this.maxNestingDepth = Math.max(this.maxNestingDepth, this.depth);
+    const result = this.visitChildren(ctx);
+    this.depth--;
+    return result;
+  }
+}
+
+/**
+ * Report structural complexity metrics for Spark SQL.
+ * Multi-statement input is summed across statements (nesting depth is the
+ * maximum). Statements with any syntax error contribute nothing.
+ */
+export function analyzeSql(sql: string): QueryMetrics {
+  const visitor = new MetricsVisitor();
+
+  for (const span of getStatementSpans(sql)) {
+    if (!span.text) continue;
+    // ${var} substitutions parse as identifiers, same as in formatSql
+    const { sql: statement } = extractVariables(span.text);
+    const errorCounter = new ErrorCounter();
+    const lexer = new SqlBaseLexer(new antlr4.InputStream(statement));
+    // @ts-expect-error - ANTLR types incomplete
+    lexer.removeErrorListeners?.();
+    // @ts-expect-error - ANTLR types incomplete
+    lexer.addErrorListener?.(errorCounter);
+    const parser = new SqlBaseParser(new antlr4.CommonTokenStream(lexer));
+    // @ts-expect-error - ANTLR types incomplete
+    parser.removeErrorListeners?.();
+    // @ts-expect-error - ANTLR types incomplete
+    parser.addErrorListener?.(errorCounter);
+
+    let tree: any;
+    try {
+      tree = parser.singleStatement();
+    } catch {
+      // Unparseable statement: skip it
+      continue;
+    }
+    // Error recovery still builds a tree; don't count a partial one
+    if (errorCounter.errors > 0) continue;
+    visitor.visit(tree);
+  }
+
+  return {
+    joins: visitor.joins,
+    subqueries: visitor.subqueries,
+    ctes: visitor.ctes,
+    maxNestingDepth: visitor.maxNestingDepth,
+    selectStar: visitor.selectStar,
+  };
+}
@@ -6,6 +6,7 @@
  */
 
 import { indentCalc } from './formatting-context.js';
+import { isOrderLikeClauseKeyword } from './token-utils.js';
 import type { AnalyzerResult, ExpandedPivot, ExpandedWindow } from './types.js';
 
 /**
@@ -130,7 +131,7 @@ export function calculateNewlineAndIndent(
     state.afterGroupByKeyword = true;
     state.isFirstListItem = true;
     state.currentClauseIsMultiItem = analysis.multiItemClauses.has(tokenIndex);
-  } else if (symbolicName === 'ORDER' && ctx.isClauseStart) {
+  } else if (isOrderLikeClauseKeyword(symbolicName) && ctx.isClauseStart) {
     state.afterOrderByKeyword = true;
     state.isFirstListItem = true;
     state.currentClauseIsMultiItem = analysis.multiItemClauses.has(tokenIndex);
@@ -373,7 +374,7 @@ export function calculateNewlineAndIndent(
     if (
       symbolicName !== 'SELECT' &&
       symbolicName !== 'GROUP' &&
-      symbolicName !== 'ORDER'
+      !isOrderLikeClauseKeyword(symbolicName)
     ) {
       if (
         (state.afterGroupByKeyword && symbolicName === 'BY') ||
@@ -189,9 +189,13 @@ export function shouldSkipSpace(
   const prevWasMemberAccessDot = lastChar === '.' && context.prevWasDotToken;
 
   // IMPORTANT: Don't skip space between consecutive unary operators like "- -5"
-  // Otherwise it becomes "--5" which is a line comment!
+  // Otherwise it becomes "--5" which is a line comment! Only sign pairs are
+  // at risk, so "!!a" and "~-a" stay tight.
   const prevWasUnaryAndCurrentIsUnary =
-    context.prevTokenWasUnaryOperator && context.currentTokenIsUnaryOperator;
+    context.prevTokenWasUnaryOperator &&
+    context.currentTokenIsUnaryOperator &&
+    (text === '-' || text === '+') &&
+    (context.prevTokenText === '-' || context.prevTokenText === '+');
 
   // Inside complex types (ARRAY<INT>, MAP<STRING, INT>, STRUCT<a:INT>)
   // Skip spaces around angle brackets and before commas
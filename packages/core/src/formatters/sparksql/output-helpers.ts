@@ -12,7 +12,11 @@ import {
   shouldAddCommaSpace,
   shouldSkipSpace,
 } from './output-builder.js';
-import { getSymbolicName, SqlBaseLexer } from './token-utils.js';
+import {
+  getSymbolicName,
+  isOrderLikeClauseKeyword,
+  SqlBaseLexer,
+} from './token-utils.js';
 
 /**
  * Interface for state required by output functions.
@@ -116,7 +120,8 @@ export function outputWithoutNewline(
         state.prevTokenWasUnaryOperator &&
         (state.prevTokenText === '-' ||
           state.prevTokenText === '+' ||
-          state.prevTokenText === '~'),
+          state.prevTokenText === '~' ||
+          state.prevTokenText === '!'),
       currentTokenIsUnaryOperator,
       isLateralViewComma,
       prevIsDoubleColon,
@@ -167,7 +172,7 @@ export function updateClauseFlags(
     state.afterGroupByKeyword = false;
   }
   if (
-    symbolicName !== 'ORDER' &&
+    !isOrderLikeClauseKeyword(symbolicName) &&
     symbolicName !== 'BY' &&
     state.afterOrderByKeyword &&
     !ctx.isListComma
@@ -26,7 +26,7 @@ import {
   calculateNormalizedSpanLength,
   calculateSpanLength,
 } from './span-calculator.js';
-import { getTokenType } from './token-utils.js';
+import { getTokenType, isOrderLikeClauseKeyword } from './token-utils.js';
 import type {
   AnalyzerResult,
   InListInfo,
@@ -87,6 +87,7 @@ export class ParseTreeAnalyzer extends SqlBaseParserVisitor {
   valuesHasTuples: boolean = false; // true if VALUES contains tuples like (a, b), (c, d)
   setClauseCommas: Set<number> = new Set();
   setKeywordToken: number = -1;
+  inlineTableDepth: number = 0; // > 0 inside a VALUES row list
 
   // CASE expression handling
   multiWhenCaseTokens: Set<number> = new Set();
@@ -305,7 +306,12 @@ export class ParseTreeAnalyzer extends SqlBaseParserVisitor {
   // ========== FUNCTION CALL CONTEXTS ==========
 
   visitFunctionCall(ctx: any): any {
-    if (ctx.start) {
+    // Qualified names (cat.db.func) are marked by visitFunctionName instead,
+    // so the leading catalog/schema segments keep identifier casing
+    const nameCtx = ctx.children?.[0];
+    const isQualified =
+      nameCtx?.stop && nameCtx.stop.tokenIndex !== nameCtx.start?.tokenIndex;
+    if (ctx.start && !isQualified) {
       this.functionCallTokens.add(ctx.start.tokenIndex);
     }
 
@@ -319,8 +325,10 @@ export class ParseTreeAnalyzer extends SqlBaseParserVisitor {
   }
 
   visitFunctionName(ctx: any): any {
-    if (ctx.start) {
-      this.functionCallTokens.add(ctx.start.tokenIndex);
+    // Mark the final segment: it is the one directly before the paren
+    const nameToken = ctx.stop ?? ctx.start;
+    if (nameToken) {
+      this.functionCallTokens.add(nameToken.tokenIndex);
     }
     return this.visitChildren(ctx);
   }
@@ -386,6 +394,16 @@ export class ParseTreeAnalyzer extends SqlBaseParserVisitor {
     return this.visitChildren(ctx);
   }
 
+  visitPrimitiveDataType(ctx: any): any {
+    // Parameterized types (DECIMAL(10, 2), VARCHAR(20)) take no space before
+    // the paren, same as a function call
+    const hasParams = this._findTokenInContext(ctx, 'LEFT_PAREN') !== null;
+    if (hasParams && ctx.start) {
+      this.functionCallTokens.add(ctx.start.tokenIndex);
+    }
+    return this.visitChildren(ctx);
+  }
+
   visitPosition(ctx: any): any {
     if (ctx.start) this.functionCallTokens.add(ctx.start.tokenIndex);
     return this.visitChildren(ctx);
@@ -529,24 +547,41 @@ export class ParseTreeAnalyzer extends SqlBaseParserVisitor {
   }
 
   visitQueryOrganization(ctx: any): any {
-    let orderTokenIndex: number | null = null;
+    // ORDER BY and the Hive-style CLUSTER/DISTRIBUTE/SORT BY clauses each own
+    // their comma list, so attribute commas to the clause they follow
+    let currentClauseToken: number | null = null;
     if (ctx.children) {
       for (const child of ctx.children) {
         if (child.symbol) {
           const symName = SqlBaseLexer.symbolicNames[child.symbol.type];
-          if (symName === 'ORDER') {
+          if (isOrderLikeClauseKeyword(symName)) {
             this.clauseStartTokens.add(child.symbol.tokenIndex);
-            orderTokenIndex = child.symbol.tokenIndex;
+            currentClauseToken = child.symbol.tokenIndex;
           } else if (symName === 'LIMIT') {
             this.clauseStartTokens.add(child.symbol.tokenIndex);
+            currentClauseToken = null;
+          } else if (symName === 'OFFSET') {
+            currentClauseToken = null;
+          } else if (symName === 'COMMA' && currentClauseToken !== null) {
+            this.listItemCommas.add(child.symbol.tokenIndex);
+            this.multiItemClauses.add(currentClauseToken);
+          }
+        } else if (child.ruleIndex !== undefined) {
+          // Anything other than a sort/expression item (e.g. the WINDOW
+          // clause) ends the current list; its own commas stay inline
+          const ruleName = SqlBaseParser.ruleNames[child.ruleIndex];
+          if (ruleName !== 'sortItem' && ruleName !== 'expression') {
+            currentClauseToken = null;
+          }
+          if (currentClauseToken === null) continue;
+          const commaCount =
+            this._markListCommasExcludingGroupingAnalytics(child);
+          if (commaCount > 0) {
+            this.multiItemClauses.add(currentClauseToken);
           }
         }
       }
     }
-    const commaCount = this._markListCommasExcludingGroupingAnalytics(ctx);
-    if (commaCount > 0 && orderTokenIndex !== null) {
-      this.multiItemClauses.add(orderTokenIndex);
-    }
     return this.visitChildren(ctx);
   }
 
@@ -597,6 +632,7 @@ export class ParseTreeAnalyzer extends SqlBaseParserVisitor {
           if (
             symName === 'UNION' ||
             symName === 'EXCEPT' ||
+            symName === 'SETMINUS' || // MINUS, a synonym for EXCEPT
             symName === 'INTERSECT'
           ) {
             this.clauseStartTokens.add(child.symbol.tokenIndex);
@@ -855,6 +891,25 @@ export class ParseTreeAnalyzer extends SqlBaseParserVisitor {
 
   visitInlineTable(ctx: any): any {
     this._markValuesCommas(ctx);
+    this.inlineTableDepth++;
+    const result = this.visitChildren(ctx);
+    this.inlineTableDepth--;
+    return result;
+  }
+
+  /**
+   * DEFAULT in a VALUES row parses as a column reference, but Spark treats
+   * it as the column-default keyword. Skip identifier marking so it is
+   * uppercased like other keywords.
+   */
+  visitColumnReference(ctx: any): any {
+    if (
+      this.inlineTableDepth > 0 &&
+      ctx.start === ctx.stop &&
+      ctx.start?.text?.toUpperCase() === 'DEFAULT'
+    ) {
+      return null;
+    }
     return this.visitChildren(ctx);
   }
 
@@ -1885,13 +1940,19 @@ export class ParseTreeAnalyzer extends SqlBaseParserVisitor {
     }
   }
 
-  private _markListCommasExcludingGroupingAnalytics(ctx: any): number {
+  private _markListCommasExcludingGroupingAnalytics(
+    ctx: any,
+    insideGroupingAnalytics = false,
+  ): number {
     let count = 0;
     if (!ctx || !ctx.children) return 0;
 
+    // Commas anywhere inside GROUPING SETS/ROLLUP/CUBE stay inline,
+    // including those in nested sets like GROUPING SETS ((a, b), (a))
     const isGroupingAnalytics =
-      ctx.ruleIndex !== undefined &&
-      SqlBaseParser.ruleNames[ctx.ruleIndex] === 'groupingAnalytics';
+      insideGroupingAnalytics ||
+      (ctx.ruleIndex !== undefined &&
+        SqlBaseParser.ruleNames[ctx.ruleIndex] === 'groupingAnalytics');
 
     for (const child of ctx.children) {
       if (child.symbol) {
@@ -1902,7 +1963,10 @@ export class ParseTreeAnalyzer extends SqlBaseParserVisitor {
           count++;
         }
       } else if (child.ruleIndex !== undefined) {
-        count += this._markListCommasExcludingGroupingAnalytics(child);
+        count += this._markListCommasExcludingGroupingAnalytics(
+          child,
+          isGroupingAnalytics,
+        );
       }
     }
     return count;
@@ -2052,9 +2116,13 @@ export class ParseTreeAnalyzer extends SqlBaseParserVisitor {
   private _markDdlColumnList(ctx: any): void {
     if (!ctx || !ctx.children) return;
     let foundLeftParen = false;
+    let foundRightParen = false;
     let leftParenIndex = -1;
     let commaCount = 0;
     for (const child of ctx.children) {
+      // Clauses after the column list (OPTIONS, TBLPROPERTIES, ...) have
+      // their own commas that must not split the statement
+      if (foundRightParen) break;
       if (child.symbol) {
         const tokenType = child.symbol.type;
         if (tokenType === getTokenType('LEFT_PAREN') && !foundLeftParen) {
@@ -2066,6 +2134,7 @@ export class ParseTreeAnalyzer extends SqlBaseParserVisitor {
           foundLeftParen
         ) {
           this.ddlCloseParens.add(child.symbol.tokenIndex);
+          foundRightParen = true;
         } else if (tokenType === getTokenType('COMMA') && foundLeftParen) {
           this.ddlColumnCommas.add(child.symbol.tokenIndex);
           commaCount++;
@@ -2096,6 +2165,12 @@ export class ParseTreeAnalyzer extends SqlBaseParserVisitor {
           this.ddlColumnCommas.add(child.symbol.tokenIndex);
           count++;
         }
+      } else if (
+        child.ruleIndex !== undefined &&
+        SqlBaseParser.ruleNames[child.ruleIndex] === 'primitiveDataType'
+      ) {
+        // Type parameters like DECIMAL(10, 2) are not column separators
+        continue;
       } else if (child.children) {
         count += this._markDdlCommasInContext(child, angleDepth);
       }
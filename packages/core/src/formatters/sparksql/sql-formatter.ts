@@ -9,7 +9,7 @@ import type {
   FormatterOptions,
   LanguageFormatter,
 } from '../types.js';
-import { formatSql, needsFormatting } from './index.js';
+import { formatSqlChecked, needsFormatting } from './index.js';
 
 /**
  * SQL formatter for Spark SQL.
@@ -29,10 +29,7 @@ export class SqlFormatter implements LanguageFormatter {
 
   format(code: string, _options?: FormatterOptions): FormatResult {
     try {
-      const formatted = formatSql(code);
-
-      const changed = formatted !== code;
-      return { formatted, changed };
+      return formatSqlChecked(code);
     } catch (error) {
       return {
         formatted: code,
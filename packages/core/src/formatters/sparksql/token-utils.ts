@@ -131,6 +131,21 @@ export function isWhitespaceToken(tokenType: number): boolean {
   return tokenType === SqlBaseLexer.WS;
 }
 
+/**
+ * Check if a keyword starts an ordering/distribution clause.
+ * ORDER BY and the Hive-style CLUSTER/DISTRIBUTE/SORT BY share list layout.
+ */
+export function isOrderLikeClauseKeyword(
+  symbolicName: string | null,
+): boolean {
+  return (
+    symbolicName === 'ORDER' ||
+    symbolicName === 'CLUSTER' ||
+    symbolicName === 'DISTRIBUTE' ||
+    symbolicName === 'SORT'
+  );
+}
+
 // ============================================================================
 // FUNCTION-LIKE KEYWORDS
 // ============================================================================
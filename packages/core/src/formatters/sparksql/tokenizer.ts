@@ -0,0 +1,67 @@
+/**
+ * Tokenizer - Public Token Stream
+ *
+ * Exposes the Spark SQL lexer for consumers that only need tokens
+ * (e.g., syntax highlighting) without parsing or formatting.
+ *
+ * Token kinds are grammar-derived, using the same detection as the formatter.
+ * Classification is lexical: a keyword used as a column name (a.order)
+ * is still reported as a keyword.
+ */
+
+import antlr4 from 'antlr4';
+import {
+  getSymbolicName,
+  isCommentToken,
+  isKeywordToken,
+  isWhitespaceToken,
+  SqlBaseLexer,
+} from './token-utils.js';
+import type { SqlToken, SqlTokenKind } from './types.js';
+
+/**
+ * Classify a lexer token into a highlighting kind.
+ */
+function getTokenKind(tokenType: number, text: string): SqlTokenKind {
+  if (isWhitespaceToken(tokenType)) return 'whitespace';
+  if (isCommentToken(tokenType)) return 'comment';
+  if (isKeywordToken(tokenType, text)) return 'keyword';
+
+  const symbolicName = getSymbolicName(tokenType) ?? '';
+  if (
+    symbolicName === 'IDENTIFIER' ||
+    symbolicName === 'BACKQUOTED_IDENTIFIER'
+  ) {
+    return 'identifier';
+  }
+  // STRING_LITERAL, DOUBLEQUOTED_STRING, INTEGER_VALUE, DECIMAL_VALUE, BIGINT_LITERAL, ...
+  if (
+    symbolicName.endsWith('_LITERAL') ||
+    symbolicName.endsWith('_VALUE') ||
+    symbolicName.includes('STRING')
+  ) {
+    return 'literal';
+  }
+  return 'operator';
+}
+
+/**
+ * Tokenize Spark SQL, including whitespace and comments.
+ * Offsets are character positions: start is inclusive, end is exclusive.
+ */
+export function* tokenize(sql: string): IterableIterator<SqlToken> {
+  const lexer = new SqlBaseLexer(new antlr4.InputStream(sql));
+  (lexer as any).removeErrorListeners?.();
+  const tokens = new antlr4.CommonTokenStream(lexer);
+  tokens.fill();
+
+  for (const token of (tokens as any).tokens) {
+    if (token.type === antlr4.Token.EOF) continue;
+    yield {
+      kind: getTokenKind(token.type, token.text),
+      text: token.text,
+      start: token.start,
+      end: token.stop + 1,
+    };
+  }
+}
@@ -310,10 +310,67 @@ export interface PendingComment {
   hadBlankLineBefore: boolean;
 }
 
+// ============================================================================
+// SOURCE SPAN TYPES
+// ============================================================================
+
+/**
+ * Location of a single statement within the original input.
+ * Offsets are character positions: start is inclusive, end is exclusive.
+ * Surrounding whitespace and the separating semicolon are not included.
+ */
+export interface StatementSpan {
+  start: number;
+  end: number;
+  text: string;
+}
+
+// ============================================================================
+// QUERY METRICS TYPES
+// ============================================================================
+
+/**
+ * Structural complexity metrics returned by analyzeSql().
+ */
+export interface QueryMetrics {
+  /** Explicit JOIN clauses */
+  joins: number;
+  /** FROM, scalar, EXISTS, and IN subqueries */
+  subqueries: number;
+  /** CTE definitions in WITH clauses */
+  ctes: number;
+  /** Deepest nesting of subqueries and CTE bodies (0 = no nesting) */
+  maxNestingDepth: number;
+  /** Whether any select list uses * or t.* */
+  selectStar: boolean;
+}
+
 // ============================================================================
 // TOKEN TYPES
 // ============================================================================
 
+/**
+ * Kind of a token returned by tokenize().
+ */
+export type SqlTokenKind =
+  | 'keyword'
+  | 'identifier'
+  | 'literal'
+  | 'operator'
+  | 'comment'
+  | 'whitespace';
+
+/**
+ * A single token returned by tokenize().
+ * Offsets are character positions: start is inclusive, end is exclusive.
+ */
+export interface SqlToken {
+  kind: SqlTokenKind;
+  text: string;
+  start: number;
+  end: number;
+}
+
 /**
  * Context information for a single token during formatting.
  */
@@ -18,7 +18,19 @@
 // SQL Formatter (Core API)
 // ============================================================================
 
-export { formatSql, needsFormatting } from './formatters/sparksql/index.js';
+export {
+  analyzeSql,
+  formatSql,
+  formatSqlChecked,
+  formatSqlRange,
+  getStatementSpans,
+  needsFormatting,
+  type QueryMetrics,
+  type SqlToken,
+  type SqlTokenKind,
+  type StatementSpan,
+  tokenize,
+} from './formatters/sparksql/index.js';
 
 // ============================================================================
 // Language Formatters (Extensible)
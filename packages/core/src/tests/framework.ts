@@ -19,8 +19,10 @@ export interface TestCase {
   name: string;
   input: string;
   expected: string;
-  /** Optional custom validator for complex checks like idempotency */
+  /** Optional custom validator for complex checks */
   customValidator?: CustomValidator;
+  /** Also require that formatting the output again leaves it unchanged */
+  idempotent?: boolean;
 }
 
 export interface TestSuite {
@@ -67,6 +69,14 @@ export function runSuite(
       success = result === tc.expected;
     }
 
+    if (success && tc.idempotent) {
+      const pass2 = formatFn(result);
+      if (pass2 !== result) {
+        success = false;
+        message = `Not idempotent!\nPass 1: ${JSON.stringify(result)}\nPass 2: ${JSON.stringify(pass2)}`;
+      }
+    }
+
     if (success) {
       passed++;
       results.push({ name: tc.name, passed: true });
@@ -5,12 +5,20 @@
  */
 
 import { formatCell, initializePythonFormatter } from '../cell-formatter.js';
-import { formatSql } from '../formatters/sparksql/index.js';
+import {
+  analyzeSql,
+  formatSql,
+  formatSqlChecked,
+  getStatementSpans,
+  needsFormatting,
+  tokenize,
+} from '../formatters/sparksql/index.js';
 import {
   printSuiteResult,
   printSummary,
   runSuite,
   type SuiteResult,
+  type TestSuite,
 } from './framework.js';
 // Import integration test suites
 import {
@@ -33,6 +41,8 @@ import {
 import {
   basicSelectTests,
   tablesampleTests,
+  timeTravelTests,
+  trailingCommaTests,
 } from './sparksql/basic-select.test.js';
 import {
   aliasTests,
@@ -46,16 +56,27 @@ import { deltaLakeTests } from './sparksql/delta-lake.test.js';
 import { dmlTests } from './sparksql/dml.test.js';
 import {
   arrayAccessTests,
+  binaryOperatorTests,
   caseExpressionTests,
+  intervalTests,
   lambdaTests,
   literalTests,
+  namedArgumentTests,
   nestedFunctionTests,
+  parameterMarkerTests,
+  structConstructorTests,
   unaryOperatorTests,
+  variableSubstitutionTests,
 } from './sparksql/expressions.test.js';
+import { formatCheckedTests } from './sparksql/format-checked.test.js';
 import {
   fmtInlineTests,
   fmtOffTests,
 } from './sparksql/format-directives.test.js';
+import {
+  formatMarkedRange,
+  formatRangeTests,
+} from './sparksql/format-range.test.js';
 import { distributionTests, groupByTests } from './sparksql/grouping.test.js';
 import { joinTests } from './sparksql/joins.test.js';
 import { magicCommandsTests } from './sparksql/magic-commands.test.js';
@@ -65,7 +86,12 @@ import {
   runValidationSuite,
   validationSuite,
 } from './sparksql/magic-sql.test.js';
-import { semicolonTests } from './sparksql/semicolon.test.js';
+import { queryMetricsTests } from './sparksql/metrics.test.js';
+import {
+  emptyInputTests,
+  semicolonTests,
+  statementSpanTests,
+} from './sparksql/semicolon.test.js';
 import {
   cteTests,
   setOperationTests,
@@ -78,10 +104,15 @@ import {
   stackTests,
   unpivotTests,
 } from './sparksql/table-operators.test.js';
+import {
+  tokenizerTests,
+  tokenOffsetTests,
+} from './sparksql/tokenizer.test.js';
 import { castTests, doubleColonCastTests } from './sparksql/type-casts.test.js';
 import { utilityTests } from './sparksql/utility.test.js';
 import { selectExceptTests, whereTests } from './sparksql/where.test.js';
 import {
+  aggregateSuffixTests,
   namedWindowTests,
   nullHandlingTests,
   windowFunctionTests,
@@ -92,6 +123,8 @@ const sparkSqlSuites = [
   // Core SELECT
   basicSelectTests,
   tablesampleTests,
+  timeTravelTests,
+  trailingCommaTests,
 
   // Casing
   casingTests,
@@ -115,8 +148,14 @@ const sparkSqlSuites = [
   caseExpressionTests,
   literalTests,
   unaryOperatorTests,
+  binaryOperatorTests,
+  intervalTests,
   arrayAccessTests,
+  structConstructorTests,
   lambdaTests,
+  namedArgumentTests,
+  parameterMarkerTests,
+  variableSubstitutionTests,
   nestedFunctionTests,
 
   // Type casts
@@ -127,6 +166,7 @@ const sparkSqlSuites = [
   windowFunctionTests,
   namedWindowTests,
   nullHandlingTests,
+  aggregateSuffixTests,
 
   // Table operators
   pivotTests,
@@ -146,6 +186,7 @@ const sparkSqlSuites = [
   utilityTests,
   magicCommandsTests,
   semicolonTests,
+  emptyInputTests,
 
   // Formatting control
   fmtOffTests,
@@ -156,6 +197,33 @@ const sparkSqlSuites = [
   deltaLakeTests,
 ];
 
+// Suites for APIs other than formatSql, each run with the function under
+// test; results are JSON-encoded for comparison with the expected value
+const apiSuites: [TestSuite, (input: string) => string][] = [
+  [statementSpanTests, (sql) => JSON.stringify(getStatementSpans(sql))],
+  [formatRangeTests, formatMarkedRange],
+  [
+    formatCheckedTests,
+    (sql) => {
+      const { formatted, changed } = formatSqlChecked(sql);
+      return JSON.stringify({
+        formatted,
+        changed,
+        needsFormatting: needsFormatting(sql),
+      });
+    },
+  ],
+  [
+    tokenizerTests,
+    (sql) => JSON.stringify(Array.from(tokenize(sql), (t) => [t.kind, t.text])),
+  ],
+  [
+    tokenOffsetTests,
+    (sql) => JSON.stringify(Array.from(tokenize(sql), (t) => [t.start, t.end])),
+  ],
+  [queryMetricsTests, (sql) => JSON.stringify(analyzeSql(sql))],
+];
+
 // Python test suites (sync tests)
 const pythonSyncSuites = [
   basicFormattingTests,
@@ -182,6 +250,11 @@ async function main(): Promise<void> {
     results.push(result);
     printSuiteResult(result, verbose);
   }
+  for (const [suite, formatFn] of apiSuites) {
+    const result = runSuite(suite, formatFn);
+    results.push(result);
+    printSuiteResult(result, verbose);
+  }
   if (timing)
     console.log(
       `  ⏱ Spark SQL tests: ${(performance.now() - sqlStart).toFixed(0)}ms`,
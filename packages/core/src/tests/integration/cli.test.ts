@@ -674,6 +674,55 @@ y=2
       };
     },
   },
+  {
+    name: 'Stdin trailing newline is kept on output but not formatted',
+    test: async () => {
+      const inline = await runCli('format --type sparksql -i "select 1"');
+      const piped = await runCli('format --type sparksql', 'select 1\n');
+      return {
+        passed: inline.stdout === 'SELECT 1' && piped.stdout === 'SELECT 1\n',
+        message: `Expected "SELECT 1" and "SELECT 1\\n", got ${JSON.stringify(inline.stdout)} and ${JSON.stringify(piped.stdout)}`,
+      };
+    },
+  },
+  {
+    name: 'check passes formatted stdin that ends with a newline',
+    test: async () => {
+      const result = await runCli('check --type sparksql', 'SELECT * FROM t\n');
+      return {
+        passed: result.exitCode === 0,
+        message: `check should ignore the final newline, got exit code ${result.exitCode}`,
+      };
+    },
+  },
+  {
+    name: 'Stdin BOM is stripped before formatting',
+    test: async () => {
+      const result = await runCli(
+        'format --type sparksql',
+        '\uFEFFselect * from t\r\n',
+      );
+      return {
+        passed: result.stdout === 'SELECT * FROM t\n',
+        message: `Expected "SELECT * FROM t\\n", got ${JSON.stringify(result.stdout)}`,
+      };
+    },
+  },
+  {
+    name: 'check fails formatted stdin with CRLF or a BOM',
+    test: async () => {
+      // format would rewrite these bytes, so check must not pass them
+      const crlf = await runCli('check --type sparksql', 'SELECT * FROM t\r\n');
+      const bom = await runCli(
+        'check --type sparksql',
+        '\uFEFFSELECT * FROM t',
+      );
+      return {
+        passed: crlf.exitCode === 1 && bom.exitCode === 1,
+        message: `Expected exit 1 for both, got ${crlf.exitCode} (CRLF) and ${bom.exitCode} (BOM)`,
+      };
+    },
+  },
 
   // No files specified
   {
@@ -826,6 +875,77 @@ y=2
       }
     },
   },
+  {
+    name: 'Format failure in one file does not stop the others',
+    test: async () => {
+      const tempDir = createTempDir();
+      try {
+        // Raw python cell in a .sql file is an invalid notebook structure
+        const badContent = `-- Fabric notebook source\n\n-- CELL ********************\n\nx=1\n\n-- METADATA ********************\n\n-- META {\n-- META   "language": "python"\n-- META }\n`;
+        const goodContent = `-- Fabric notebook source\n\n-- CELL ********************\n\nselect 1\n\n-- METADATA ********************\n\n-- META {\n-- META   "language": "sparksql"\n-- META }\n`;
+        const badFile = join(tempDir, 'bad.sql');
+        const goodFile = join(tempDir, 'good.sql');
+        writeFileSync(badFile, badContent);
+        writeFileSync(goodFile, goodContent);
+
+        // Bad file first, so the good one only runs if errors are per-file
+        const result = await runCli(`format "${badFile}" "${goodFile}"`);
+
+        const goodFormatted = readFileSync(goodFile, 'utf-8').includes(
+          'SELECT 1',
+        );
+        const badUnchanged = readFileSync(badFile, 'utf-8') === badContent;
+        const errorReported = result.stderr.includes(
+          `Error formatting ${badFile}`,
+        );
+
+        return {
+          passed:
+            goodFormatted &&
+            badUnchanged &&
+            errorReported &&
+            result.exitCode === 1,
+          message: `Expected good file formatted, bad file reported and exit 1; got exit ${result.exitCode}, stderr: ${result.stderr}`,
+        };
+      } finally {
+        cleanupTempDir(tempDir);
+      }
+    },
+  },
+  {
+    name: 'Check failure in one file does not stop the others',
+    test: async () => {
+      const tempDir = createTempDir();
+      try {
+        // Raw python cell in a .sql file is an invalid notebook structure
+        const badContent = `-- Fabric notebook source\n\n-- CELL ********************\n\nx=1\n\n-- METADATA ********************\n\n-- META {\n-- META   "language": "python"\n-- META }\n`;
+        const goodContent = `-- Fabric notebook source\n\n-- CELL ********************\n\nselect 1\n\n-- METADATA ********************\n\n-- META {\n-- META   "language": "sparksql"\n-- META }\n`;
+        const badFile = join(tempDir, 'bad.sql');
+        const goodFile = join(tempDir, 'good.sql');
+        writeFileSync(badFile, badContent);
+        writeFileSync(goodFile, goodContent);
+
+        // Bad file first, so the good one only runs if errors are per-file
+        const result = await runCli(`check "${badFile}" "${goodFile}"`);
+
+        const goodListed = result.stdout.includes(`${goodFile}\n`);
+        const errorReported = result.stderr.includes(
+          `Error checking ${badFile}`,
+        );
+        const summarized = result.stdout.includes(
+          'Checked 1 of 2 file(s), 1 failed',
+        );
+
+        return {
+          passed:
+            goodListed && errorReported && summarized && result.exitCode === 1,
+          message: `Expected good file listed, bad file reported and exit 1; got exit ${result.exitCode}, stdout: ${result.stdout}, stderr: ${result.stderr}`,
+        };
+      } finally {
+        cleanupTempDir(tempDir);
+      }
+    },
+  },
   {
     name: 'Finds files at multiple nesting levels',
     test: async () => {
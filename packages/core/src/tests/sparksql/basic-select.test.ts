@@ -31,11 +31,78 @@ export const basicSelectTests: TestSuite = {
       input: 'select t.* from t',
       expected: 'SELECT t.* FROM t', // Simple query stays on one line
     },
+    {
+      name: 'Multi-part qualified star',
+      input: 'select db.tbl.* from db.tbl',
+      expected: 'SELECT db.tbl.* FROM db.tbl',
+    },
+    {
+      name: 'Backticked qualified star',
+      input: 'select `my t`.* from tbl as `my t`',
+      expected: 'SELECT `my t`.* FROM tbl `my t`',
+    },
+    {
+      name: 'Qualified stars in multi-column select',
+      input: 'select a.*, db.b.* from a join db.b on a.id = b.id',
+      expected:
+        'SELECT\n     a.*\n    ,db.b.*\nFROM a\nJOIN db.b\n    ON a.id = b.id',
+      idempotent: true,
+    },
+    {
+      name: 'Qualified star as a COUNT argument',
+      input:
+        'select count(t.*), count(*), count(t.id) from t join u on t.id = u.id',
+      expected:
+        'SELECT\n     COUNT(t.*)\n    ,COUNT(*)\n    ,COUNT(t.id)\nFROM t\nJOIN u\n    ON t.id = u.id',
+      idempotent: true,
+    },
+    {
+      name: 'Backticked qualified star as a COUNT argument',
+      input: 'select count(`my t`.*) from tbl as `my t`',
+      expected: 'SELECT COUNT(`my t`.*) FROM tbl `my t`',
+    },
     {
       name: 'LIMIT clause',
       input: 'select * from t limit 10',
       expected: 'SELECT * FROM t LIMIT 10', // Simple query stays on one line
     },
+    {
+      name: 'LIMIT with arithmetic expression',
+      input: 'select a, b from t order by a limit 5+5',
+      expected: 'SELECT\n     a\n    ,b\nFROM t\nORDER BY a\nLIMIT 5 + 5',
+      idempotent: true,
+    },
+    {
+      name: 'LIMIT literal in multiline query',
+      input: 'select a, b from t order by a limit 10',
+      expected: 'SELECT\n     a\n    ,b\nFROM t\nORDER BY a\nLIMIT 10',
+    },
+    {
+      name: 'LIMIT ALL preserved',
+      input: 'select * from t limit all',
+      expected: 'SELECT * FROM t LIMIT ALL',
+    },
+    {
+      name: 'LIMIT ALL on its own line in multiline query',
+      input: 'select a, b from t order by a limit all',
+      expected: 'SELECT\n     a\n    ,b\nFROM t\nORDER BY a\nLIMIT ALL',
+    },
+    {
+      name: 'SELECT without FROM (single expression)',
+      input: 'select 1 + 1',
+      expected: 'SELECT 1 + 1',
+    },
+    {
+      name: 'SELECT without FROM (function call)',
+      input: 'select current_date()',
+      expected: 'SELECT CURRENT_DATE()',
+    },
+    {
+      name: 'SELECT without FROM (multiple columns)',
+      input: 'select 1 as x, 2 as y',
+      expected: 'SELECT\n     1 AS x\n    ,2 AS y',
+      idempotent: true,
+    },
     {
       name: 'Complex query with multiple clauses',
       input:
@@ -54,6 +121,18 @@ export const tablesampleTests: TestSuite = {
       input: 'select * from t tablesample (10 percent)',
       expected: 'SELECT * FROM t TABLESAMPLE (10 PERCENT)',
     },
+    {
+      name: 'TABLESAMPLE PERCENT with REPEATABLE seed',
+      input: 'select * from t tablesample (10 percent) repeatable (42)',
+      expected: 'SELECT * FROM t TABLESAMPLE (10 PERCENT) REPEATABLE (42)',
+      idempotent: true,
+    },
+    {
+      name: 'TABLESAMPLE REPEATABLE seed preserved verbatim',
+      input: 'select a, b from t tablesample (0.5 percent) repeatable (007)',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t TABLESAMPLE (0.5 PERCENT) REPEATABLE (007)',
+    },
     {
       name: 'TABLESAMPLE BUCKET',
       input: 'select * from t tablesample (bucket 1 out of 10)',
@@ -66,3 +145,63 @@ export const tablesampleTests: TestSuite = {
     },
   ],
 };
+
+export const timeTravelTests: TestSuite = {
+  name: 'Time Travel (AS OF)',
+  tests: [
+    {
+      name: 'VERSION AS OF',
+      input: 'select * from t version as of 3',
+      expected: 'SELECT * FROM t VERSION AS OF 3',
+    },
+    {
+      name: 'TIMESTAMP AS OF string literal',
+      input: "select * from t timestamp as of '2023-01-01'",
+      expected: "SELECT * FROM t TIMESTAMP AS OF '2023-01-01'",
+    },
+    {
+      name: 'TIMESTAMP AS OF function call',
+      input: 'select * from t timestamp as of current_timestamp()',
+      expected: 'SELECT * FROM t TIMESTAMP AS OF CURRENT_TIMESTAMP()',
+      idempotent: true,
+    },
+    {
+      name: 'VERSION AS OF in multiline query',
+      input: 'select a, b from t version as of 3 where a = 1',
+      expected: 'SELECT\n     a\n    ,b\nFROM t VERSION AS OF 3\nWHERE a = 1',
+    },
+    {
+      name: 'VERSION AS OF on joined table',
+      input:
+        'select a.id from t1 version as of 1 a join t2 timestamp as of "2023-01-01" b on a.id = b.id',
+      expected:
+        'SELECT a.id\nFROM t1 VERSION AS OF 1 a\nJOIN t2 TIMESTAMP AS OF "2023-01-01" b\n    ON a.id = b.id',
+    },
+  ],
+};
+
+export const trailingCommaTests: TestSuite = {
+  name: 'Trailing Commas (left unchanged)',
+  tests: [
+    {
+      name: 'Trailing comma before FROM',
+      input: 'select a, b, from t',
+      expected: 'select a, b, from t',
+    },
+    {
+      name: 'Trailing comma in GROUP BY',
+      input: 'select a, count(*) from t group by a,',
+      expected: 'select a, count(*) from t group by a,',
+    },
+    {
+      name: 'Trailing comma in function arguments',
+      input: 'select coalesce(a, b,) from t',
+      expected: 'select coalesce(a, b,) from t',
+    },
+    {
+      name: 'Other statements still formatted',
+      input: 'select a, b, from t; select a, b from t',
+      expected: 'select a, b, from t;\n\nSELECT\n     a\n    ,b\nFROM t',
+    },
+  ],
+};
@@ -17,6 +17,13 @@ export const casingTests: TestSuite = {
       expected:
         'SELECT\n     a.key\n    ,a.order\n    ,a.value\nFROM t\nORDER BY a.order',
     },
+    {
+      name: 'Keyword-like identifiers keep casing, quoted or not',
+      input: 'select t.Order, `Order`, `select`, Date from t order by t.Order',
+      expected:
+        'SELECT\n     t.Order\n    ,`Order`\n    ,`select`\n    ,Date\nFROM t\nORDER BY t.Order',
+      idempotent: true,
+    },
     {
       name: 'Preserve mixed case identifiers',
       input: 'select UserId, UserName from Users where IsActive = true',
@@ -46,6 +53,19 @@ export const casingTests: TestSuite = {
       input: 'select count(*), MyFunc(x), sum(y) from t',
       expected: 'SELECT\n     COUNT(*)\n    ,MyFunc(x)\n    ,SUM(y)\nFROM t',
     },
+    {
+      name: 'Two-part function name keeps namespace casing',
+      input: 'select my_catalog.MyFunc(x), hash.my_udf(a,b) from t',
+      expected:
+        'SELECT\n     my_catalog.MyFunc(x)\n    ,hash.my_udf(a, b)\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Three-part function name uppercases only a built-in final segment',
+      input: 'select cat.db.upper(x) from t',
+      expected: 'SELECT cat.db.UPPER(x) FROM t',
+      idempotent: true,
+    },
     {
       name: 'Nested functions',
       input: 'select upper(lower(trim(x))), y from t',
@@ -56,6 +76,51 @@ export const casingTests: TestSuite = {
       input: 'select coalesce(a, b, c), d from t',
       expected: 'SELECT\n     COALESCE(a, b, c)\n    ,d\nFROM t',
     },
+    {
+      name: 'Multi-word join keyword with mixed case and extra spaces',
+      input: 'select * from a left   OuTeR   join b on a.id = b.id',
+      expected: 'SELECT *\nFROM a\nLEFT OUTER JOIN b\n    ON a.id = b.id',
+      idempotent: true,
+    },
+    {
+      name: 'UNION ALL with extra spaces',
+      input: 'select a, b from t1 union  all select c, d from t2',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t1\nUNION ALL\nSELECT\n     c\n    ,d\nFROM t2',
+      idempotent: true,
+    },
+    {
+      name: 'IS NOT NULL with extra spaces',
+      input: 'select x, y from t where x is  not  null',
+      expected: 'SELECT\n     x\n    ,y\nFROM t\nWHERE x IS NOT NULL',
+      idempotent: true,
+    },
+    {
+      name: 'GROUP BY and ORDER BY with tabs and mixed case',
+      input: 'select a, count(*) from t GrOuP\tby a oRdEr  \tBY a',
+      expected: 'SELECT\n     a\n    ,COUNT(*)\nFROM t\nGROUP BY a\nORDER BY a',
+      idempotent: true,
+    },
+    {
+      name: 'Spaces inside grouping parens are removed',
+      input: 'select ( a + b ) * c, d from t',
+      expected: 'SELECT\n     (a + b) * c\n    ,d\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Spaces inside function call parens are removed',
+      input: 'select upper( name ), rand( ) from t',
+      expected: 'SELECT\n     UPPER(name)\n    ,RAND()\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Zero-argument calls stay tight',
+      input:
+        'select now( ), current_date( ), pi(), my_udf( ), row_number( ) over (order by a) from t',
+      expected:
+        'SELECT\n     NOW()\n    ,CURRENT_DATE()\n    ,PI()\n    ,my_udf()\n    ,ROW_NUMBER() OVER (ORDER BY a)\nFROM t',
+      idempotent: true,
+    },
   ],
 };
 
@@ -45,6 +45,18 @@ export const commentTests: TestSuite = {
       input: 'select x, y from t /* end comment */',
       expected: 'SELECT\n     x\n    ,y\nFROM t /* end comment */',
     },
+    {
+      name: 'Line comment at end of input without newline is stable',
+      input: 'select x, y from t where x > 1 -- trailing',
+      expected: 'SELECT\n     x\n    ,y\nFROM t\nWHERE x > 1 -- trailing',
+      idempotent: true,
+    },
+    {
+      name: 'Block comment at end of input without newline is stable',
+      input: 'select x, y from t where x > 1 /* trailing */',
+      expected: 'SELECT\n     x\n    ,y\nFROM t\nWHERE x > 1 /* trailing */',
+      idempotent: true,
+    },
 
     // === COMMENTS IN SELECT CLAUSE ===
     {
@@ -126,6 +138,27 @@ export const commentTests: TestSuite = {
       expected:
         'SELECT x\nFROM t1\nJOIN t2\n    ON /* key match */ t1.id = t2.id',
     },
+    {
+      name: 'Block comment on its own line above JOIN',
+      input: 'select x from t1\n/* dim lookup */\njoin t2 on t1.id = t2.id',
+      expected:
+        'SELECT x\nFROM t1\n/* dim lookup */\nJOIN t2\n    ON t1.id = t2.id',
+      idempotent: true,
+    },
+    {
+      name: 'Line comment on its own line above LEFT JOIN',
+      input: 'select x from t1\n-- optional\nleft join t2 on t1.id = t2.id',
+      expected:
+        'SELECT x\nFROM t1\n-- optional\nLEFT JOIN t2\n    ON t1.id = t2.id',
+    },
+    {
+      name: 'Trailing line comment after ON stays with its join',
+      input:
+        'select x from t1 join t2 on t1.id = t2.id -- business key\njoin t3 on t2.k = t3.k',
+      expected:
+        'SELECT x\nFROM t1\nJOIN t2\n    ON t1.id = t2.id -- business key\nJOIN t3\n    ON t2.k = t3.k',
+      idempotent: true,
+    },
 
     // === COMMENTS IN GROUP BY / ORDER BY ===
     {
@@ -252,6 +285,26 @@ export const commentTests: TestSuite = {
       input: 'select concat(a, /* sep */ b), c from t',
       expected: 'SELECT\n     CONCAT(a, /* sep */ b)\n    ,c\nFROM t',
     },
+    {
+      name: 'Block comment in place of a skipped arg stays inline',
+      input: 'select concat(a, /* b skipped */ c), d from t',
+      expected: 'SELECT\n     CONCAT(a, /* b skipped */ c)\n    ,d\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Line comment between function args expands the call',
+      input: 'select coalesce(a, -- fallback\nb), c from t',
+      expected:
+        'SELECT\n     COALESCE(\n         a -- fallback\n        ,b\n    )\n    ,c\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Line comment after last function arg',
+      input: 'select coalesce(a, b -- last\n), c from t',
+      expected:
+        'SELECT\n     COALESCE(\n         a\n        ,b -- last\n    )\n    ,c\nFROM t',
+      idempotent: true,
+    },
 
     // === COMMENTS IN CASE EXPRESSIONS ===
     {
@@ -266,6 +319,22 @@ export const commentTests: TestSuite = {
       expected:
         'SELECT\n     CASE WHEN /* condition */ a = 1 THEN b END\n    ,x\nFROM t',
     },
+    {
+      name: 'Trailing comment on a THEN result stays with its branch',
+      input:
+        'select case when a = 1 then 1 -- first\nwhen a = 2 then 2 end, x from t',
+      expected:
+        'SELECT\n     CASE\n        WHEN a = 1 THEN 1 -- first\n        WHEN a = 2 THEN 2\n     END\n    ,x\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Comment on its own line before WHEN is indented with the branch',
+      input:
+        'select case when a = 1 then 1\n-- second\nwhen a = 2 then 2 end, x from t',
+      expected:
+        'SELECT\n     CASE\n        WHEN a = 1 THEN 1\n        -- second\n        WHEN a = 2 THEN 2\n     END\n    ,x\nFROM t',
+      idempotent: true,
+    },
 
     // === COMMENTS IN DML ===
     {
@@ -334,6 +403,23 @@ export const commentTests: TestSuite = {
       expected:
         'WITH cte AS (\n    SELECT\n         a\n        ,b\n    FROM t\n)\n-- main query\nSELECT\n     a\n    ,b\nFROM cte',
     },
+    {
+      // A comment after the CTE comma moves above it, staying with its CTE
+      name: 'Leading comment on each of two CTEs',
+      input:
+        '-- staging\nwith staging as (select a, b from t),\n-- cleaned\ncleaned as (select a, b from staging)\nselect a, b from cleaned',
+      expected:
+        '-- staging\nWITH staging AS (\n    SELECT\n         a\n        ,b\n    FROM t\n)\n-- cleaned\n,cleaned AS (\n    SELECT\n         a\n        ,b\n    FROM staging\n)\nSELECT\n     a\n    ,b\nFROM cleaned',
+      idempotent: true,
+    },
+    {
+      name: 'Blank line before a CTE comment is preserved',
+      input:
+        'with staging as (select a, b from t),\n\n-- cleaned\ncleaned as (select a, b from staging)\nselect a, b from cleaned',
+      expected:
+        'WITH staging AS (\n    SELECT\n         a\n        ,b\n    FROM t\n)\n\n-- cleaned\n,cleaned AS (\n    SELECT\n         a\n        ,b\n    FROM staging\n)\nSELECT\n     a\n    ,b\nFROM cleaned',
+      idempotent: true,
+    },
 
     // === BUG: STANDALONE LINE COMMENTS ===
     // Comments on their own line should stay on own line, not collapse to inline
@@ -371,5 +457,33 @@ export const hintTests: TestSuite = {
       expected:
         'SELECT /*+ BROADCAST(MyTable) */\n     a\n    ,b\nFROM MyTable',
     },
+    {
+      name: 'BROADCAST hint naming a join table normalizes spacing',
+      input:
+        'select /*+   broadcast( u )  */ o.id, u.name from orders o join users u on o.uid = u.id',
+      expected:
+        'SELECT /*+ BROADCAST(u) */\n     o.id\n    ,u.name\nFROM orders o\nJOIN users u\n    ON o.uid = u.id',
+      idempotent: true,
+    },
+    {
+      name: 'Hint on the SELECT of an INSERT',
+      input: 'insert into t select /*+ repartition(4) */ a, b from s',
+      expected:
+        'INSERT INTO t\nSELECT /*+ REPARTITION(4) */\n     a\n    ,b\nFROM s',
+      idempotent: true,
+    },
+    {
+      name: 'Hint with multiple arguments normalizes spacing',
+      input: 'select /*+ repartition( 4 ,a ) */ a, b from t',
+      expected: 'SELECT /*+ REPARTITION(4, a) */\n     a\n    ,b\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Multiple hints normalize comma spacing',
+      input: 'select /*+ repartition(4, a) ,coalesce( 2 )  */ a, b from t',
+      expected:
+        'SELECT /*+ REPARTITION(4, a), COALESCE(2) */\n     a\n    ,b\nFROM t',
+      idempotent: true,
+    },
   ],
 };
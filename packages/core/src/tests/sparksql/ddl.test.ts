@@ -17,6 +17,41 @@ export const ddlTests: TestSuite = {
       expected:
         'CREATE OR REPLACE TEMP VIEW v AS\nSELECT\n     a\n    ,b\nFROM t',
     },
+    {
+      name: 'CREATE VIEW is idempotent',
+      input: 'create view vw as select a, b from t where x = 1',
+      expected:
+        'CREATE VIEW vw AS\nSELECT\n     a\n    ,b\nFROM t\nWHERE x = 1',
+      idempotent: true,
+    },
+    {
+      name: 'CREATE OR REPLACE VIEW with column list',
+      input: 'create or replace view v (id, name) as select a, b from t',
+      expected:
+        'CREATE OR REPLACE VIEW v (id, name) AS\nSELECT\n     a\n    ,b\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'CREATE TEMPORARY VIEW',
+      input: 'create temporary view v as select a, b from t',
+      expected: 'CREATE TEMPORARY VIEW v AS\nSELECT\n     a\n    ,b\nFROM t',
+      idempotent: true,
+    },
+    {
+      // The comment string is kept verbatim, quotes and case included
+      name: 'CREATE VIEW with COMMENT',
+      input: "create view v comment 'Daily Totals' as select a, b from t",
+      expected:
+        "CREATE VIEW v COMMENT 'Daily Totals' AS\nSELECT\n     a\n    ,b\nFROM t",
+      idempotent: true,
+    },
+    {
+      name: 'CREATE TABLE AS SELECT with COMMENT',
+      input: 'create table t comment "raw rows" as select a, b from s',
+      expected:
+        'CREATE TABLE t COMMENT "raw rows" AS\nSELECT\n     a\n    ,b\nFROM s',
+      idempotent: true,
+    },
     {
       name: 'Single-column CREATE TABLE (inline)',
       input: 'create table foo (id int)',
@@ -28,6 +63,37 @@ export const ddlTests: TestSuite = {
       expected:
         'CREATE TABLE foo (\n     id INT\n    ,name STRING\n    ,age INT\n)',
     },
+    {
+      // Types follow the column name with one space; no alignment
+      name: 'Column definitions with NOT NULL and COMMENT',
+      input:
+        "create table t (id int not null, name string comment 'n', ts timestamp)",
+      expected:
+        "CREATE TABLE t (\n     id INT NOT NULL\n    ,name STRING COMMENT 'n'\n    ,ts TIMESTAMP\n)",
+      idempotent: true,
+    },
+    {
+      name: 'CREATE TABLE with USING and no OPTIONS',
+      input: 'create table t (id int) using delta',
+      expected: 'CREATE TABLE t (id INT) USING delta',
+    },
+    {
+      name: 'CREATE TABLE with a single option',
+      input:
+        "create table t (id int, name string) using delta options ('path'='/data/t')",
+      expected:
+        "CREATE TABLE t (\n     id INT\n    ,name STRING\n) USING delta OPTIONS ('path' = '/data/t')",
+      idempotent: true,
+    },
+    {
+      // OPTIONS commas are not column commas
+      name: 'CREATE TABLE with multiple options keeps one-column list inline',
+      input:
+        "create table t (id int) using delta options ('a' = '1', 'B' = '2')",
+      expected:
+        "CREATE TABLE t (id INT) USING delta OPTIONS ('a' = '1', 'B' = '2')",
+      idempotent: true,
+    },
     {
       name: 'DROP TABLE IF EXISTS',
       input: 'drop table if exists my_table',
@@ -58,6 +124,41 @@ export const ddlTests: TestSuite = {
       expected:
         'CREATE TABLE t (\n     a ARRAY<ARRAY<INT>>\n    ,b MAP<STRING, STRUCT<x:INT>>\n)',
     },
+    {
+      // The comma inside DECIMAL(p, s) is not a column separator
+      name: 'DECIMAL precision and scale in DDL',
+      input: 'create table t (a decimal(10,2), b int)',
+      expected: 'CREATE TABLE t (\n     a DECIMAL(10, 2)\n    ,b INT\n)',
+      idempotent: true,
+    },
+    {
+      name: 'Single DECIMAL column stays inline',
+      input: 'create table t (a decimal(10,2))',
+      expected: 'CREATE TABLE t (a DECIMAL(10, 2))',
+    },
+    // ALTER TABLE stays on one line
+    {
+      name: 'ALTER TABLE ADD COLUMNS',
+      input: 'alter table t add columns (c int, d string)',
+      expected: 'ALTER TABLE t ADD COLUMNS (c INT, d STRING)',
+      idempotent: true,
+    },
+    {
+      name: 'ALTER TABLE DROP COLUMN',
+      input: 'alter table t drop column c',
+      expected: 'ALTER TABLE t DROP COLUMN c',
+      idempotent: true,
+    },
+    {
+      name: 'ALTER TABLE RENAME COLUMN',
+      input: 'alter table t rename column c to d',
+      expected: 'ALTER TABLE t RENAME COLUMN c TO d',
+    },
+    {
+      name: 'ALTER TABLE SET TBLPROPERTIES',
+      input: "alter table t set tblproperties ('k'='v')",
+      expected: "ALTER TABLE t SET TBLPROPERTIES ('k' = 'v')",
+    },
     // CREATE FUNCTION
     {
       name: 'CREATE FUNCTION should not have space before parens',
@@ -79,6 +180,22 @@ export const ddlTests: TestSuite = {
       expected:
         'CREATE TABLE t PARTITIONED BY (YEAR(d), MONTH(d), DAY(d), HOUR(ts), TRUNCATE(s, 10))',
     },
+    {
+      name: 'PARTITIONED BY and TBLPROPERTIES after a column list',
+      input:
+        "create table t (id int, d string) partitioned by (d) tblproperties ('k'='v', 'x'='y')",
+      expected:
+        "CREATE TABLE t (\n     id INT\n    ,d STRING\n) PARTITIONED BY (d) TBLPROPERTIES ('k' = 'v', 'x' = 'y')",
+      idempotent: true,
+    },
+    {
+      name: 'PARTITIONED BY with typed partition column',
+      input:
+        "create table t (id int) partitioned by (d string) tblproperties ('k'='v')",
+      expected:
+        "CREATE TABLE t (id INT) PARTITIONED BY (d STRING) TBLPROPERTIES ('k' = 'v')",
+      idempotent: true,
+    },
     {
       name: 'Partition transform keywords as column names stay lowercase',
       input: 'select year, month, day, bucket from t',
@@ -13,11 +13,48 @@ export const dmlTests: TestSuite = {
       expected:
         'INSERT INTO target\nSELECT\n     id\n    ,name\nFROM source\nWHERE active = TRUE',
     },
+    {
+      name: 'INSERT OVERWRITE with static partition',
+      input:
+        "insert overwrite table t partition (d='2023-01-01') select a, b from s",
+      expected:
+        "INSERT OVERWRITE TABLE t PARTITION (d = '2023-01-01')\nSELECT\n     a\n    ,b\nFROM s",
+      idempotent: true,
+    },
+    {
+      name: 'INSERT INTO with static and dynamic partition columns',
+      input: 'insert into t partition (y = 2023, m) select a, b from s',
+      expected:
+        'INSERT INTO t PARTITION (y = 2023, m)\nSELECT\n     a\n    ,b\nFROM s',
+      idempotent: true,
+    },
     {
       name: 'INSERT VALUES comma-first',
       input: "insert into t values (1, 'a'), (2, 'b')",
       expected: "INSERT INTO t VALUES\n(1, 'a')\n,(2, 'b')",
     },
+    {
+      name: 'INSERT VALUES three rows one per line',
+      input: "insert into t values (1,'a'),(2,'b'),(3,'c')",
+      expected: "INSERT INTO t VALUES\n(1, 'a')\n,(2, 'b')\n,(3, 'c')",
+    },
+    {
+      // Arity is checked by Spark's analyzer, not the parser; rows are kept as written
+      name: 'INSERT VALUES with mismatched row arity keeps every value',
+      input: "insert into t values (1, 'a'), (2), (3, 'c', true)",
+      expected: "INSERT INTO t VALUES\n(1, 'a')\n,(2)\n,(3, 'c', TRUE)",
+    },
+    {
+      name: 'INSERT VALUES with DEFAULT keyword',
+      input: 'insert into t values (default, 1), (2, default)',
+      expected: 'INSERT INTO t VALUES\n(DEFAULT, 1)\n,(2, DEFAULT)',
+      idempotent: true,
+    },
+    {
+      name: 'Column named default outside VALUES keeps its casing',
+      input: 'select default from t',
+      expected: 'SELECT default FROM t',
+    },
     {
       name: 'VALUES without SELECT (simple inline)',
       input: 'values 1, 2, 3',
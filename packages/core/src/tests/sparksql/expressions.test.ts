@@ -73,6 +73,48 @@ export const literalTests: TestSuite = {
       input: "select timestamp '2024-01-01 12:00:00', x from t",
       expected: "SELECT\n     TIMESTAMP '2024-01-01 12:00:00'\n    ,x\nFROM t",
     },
+    {
+      name: 'Multi-line string literal keeps internal newlines and indentation',
+      input:
+        "select a, 'line one\n    indented two\n  three' as s from t where b = 1",
+      expected:
+        "SELECT\n     a\n    ,'line one\n    indented two\n  three' AS s\nFROM t\nWHERE b = 1",
+      idempotent: true,
+    },
+    {
+      name: 'Scientific notation normalized outside quotes only',
+      input: "select 1.5e3, '1.5e3', `c1e2` from t",
+      expected: "SELECT\n     1.5E3\n    ,'1.5e3'\n    ,`c1e2`\nFROM t",
+      idempotent: true,
+    },
+    {
+      name: 'Hex-style 0x values preserved verbatim',
+      input: 'select 0x1F',
+      expected: 'SELECT 0x1F',
+    },
+    {
+      name: 'Hex-style 0x value with e digit is not scientific notation',
+      input: "select 0x1e5, 0x1F, X'1F2A' from t",
+      expected: "SELECT\n     0x1e5\n    ,0x1F\n    ,X'1F2A'\nFROM t",
+      idempotent: true,
+    },
+    {
+      name: 'Parenthesized row constructor',
+      input: 'select (1, 2)',
+      expected: 'SELECT (1, 2)',
+    },
+    {
+      name: 'Parenthesized arithmetic grouping',
+      input: 'select (1 + 2)',
+      expected: 'SELECT (1 + 2)',
+    },
+    {
+      name: 'Row constructor, grouping, and STRUCT side by side',
+      input: 'select (1,2) as r, ( 1+2 ) as g, struct(1, 2) as s from t',
+      expected:
+        'SELECT\n     (1, 2) AS r\n    ,(1 + 2) AS g\n    ,STRUCT(1, 2) AS s\nFROM t',
+      idempotent: true,
+    },
     // Decimal ending with dot
     {
       name: 'Decimal ending with dot should have space before FROM',
@@ -117,6 +159,130 @@ export const unaryOperatorTests: TestSuite = {
       input: 'select - -5 from t',
       expected: 'SELECT - -5 FROM t',
     },
+    // Bang as logical NOT
+    {
+      name: 'Prefix bang should not have space after it',
+      input: 'select a, b from t where ! a',
+      expected: 'SELECT\n     a\n    ,b\nFROM t\nWHERE !a',
+    },
+    {
+      name: 'Prefix bang is distinct from != operator',
+      input: 'select a, b from t where a != b and !c',
+      expected: 'SELECT\n     a\n    ,b\nFROM t\nWHERE\n    a != b\n    AND !c',
+      idempotent: true,
+    },
+    {
+      name: 'Stacked prefix bangs stay tight',
+      input: 'select a, b from t where ! ! a',
+      expected: 'SELECT\n     a\n    ,b\nFROM t\nWHERE !!a',
+      idempotent: true,
+    },
+    {
+      name: 'Prefix bang after NOT',
+      input: 'select a, b from t where not ! a',
+      expected: 'SELECT\n     a\n    ,b\nFROM t\nWHERE NOT !a',
+      idempotent: true,
+    },
+    {
+      name: 'Unary minus after NOT',
+      input: 'select not - a > 0, b from t',
+      expected: 'SELECT\n     NOT -a > 0\n    ,b\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Bang before IN is binary',
+      input: 'select a ! in (1, 2)',
+      expected: 'SELECT a ! IN (1, 2)',
+      idempotent: true,
+    },
+    {
+      name: 'Stacked bang and tilde prefixes stay tight',
+      input: 'select !!a, ~-a',
+      expected: 'SELECT\n     !!a\n    ,~-a',
+      idempotent: true,
+    },
+  ],
+};
+
+// Operators are spaced as written; parentheses are kept, never added or removed
+export const binaryOperatorTests: TestSuite = {
+  name: 'Binary Operators',
+  tests: [
+    {
+      name: 'Modulo before addition',
+      input: 'select a%b+c, d from t',
+      expected: 'SELECT\n     a % b + c\n    ,d\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'DIV integer division is uppercased',
+      input: 'select a div b, c from t',
+      expected: 'SELECT\n     a DIV b\n    ,c\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Mixed multiplicative operators keep explicit parens',
+      input: 'select a * b % c, (a + b) % c, a / (b div c) from t',
+      expected:
+        'SELECT\n     a * b % c\n    ,(a + b) % c\n    ,a / (b DIV c)\nFROM t',
+      idempotent: true,
+    },
+    {
+      // Spark has no XOR keyword; ^ is bitwise XOR (POWER() is exponent)
+      name: 'Caret is spaced as bitwise XOR',
+      input: 'select a^b, c from t',
+      expected: 'SELECT\n     a ^ b\n    ,c\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'XOR between AND and OR bitwise operators',
+      input: 'select a & b ^ c | d, (a | b) ^ c from t',
+      expected: 'SELECT\n     a & b ^ c | d\n    ,(a | b) ^ c\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Bitwise AND and OR without spaces',
+      input: 'select a&b|c, d from t',
+      expected: 'SELECT\n     a & b | c\n    ,d\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Bitwise OR is distinct from || concatenation',
+      input: 'select a|b, a||b from t',
+      expected: 'SELECT\n     a | b\n    ,a || b\nFROM t',
+      idempotent: true,
+    },
+  ],
+};
+
+export const intervalTests: TestSuite = {
+  name: 'Interval Expressions',
+  tests: [
+    {
+      name: 'Adding two intervals',
+      input: "select interval '1' day + interval '2' hour as span, x from t",
+      expected:
+        "SELECT\n     INTERVAL '1' DAY + INTERVAL '2' HOUR AS span\n    ,x\nFROM t",
+      idempotent: true,
+    },
+    {
+      name: 'Subtracting an interval from a timestamp column',
+      input: 'select ts - interval 3 days as earlier, x from t',
+      expected: 'SELECT\n     ts - INTERVAL 3 DAYS AS earlier\n    ,x\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Negative interval value (no space after minus)',
+      input: 'select ts + interval -3 day, x from t',
+      expected: 'SELECT\n     ts + INTERVAL -3 DAY\n    ,x\nFROM t',
+    },
+    {
+      name: 'Interval arithmetic in WHERE',
+      input:
+        'select a, b from t where ts > current_timestamp() - interval 1 hour',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t\nWHERE ts > CURRENT_TIMESTAMP() - INTERVAL 1 HOUR',
+    },
   ],
 };
 
@@ -168,6 +334,16 @@ export const nestedFunctionTests: TestSuite = {
       expected:
         "SELECT\n     COALESCE(\n         CONV(RIGHT(\n                 MD5(IF(\n                     LOWER(some_environment_column) IN ('dxt', 'daily', 'msit', 'prod')\n                    ,LOWER(some_environment_column)\n                    ,'n/a'\n                ))\n                ,16\n            )\n            ,16\n            ,-10\n        )\n        ,some_really_long_default_value_here\n    ) AS result\n    ,x\nFROM t",
     },
+    {
+      // A qualified name is marked on its last segment (pad_right), which is
+      // what chain detection has to look up
+      name: 'Chained opens work with a qualified inner function',
+      input:
+        "select coalesce(conv(util.pad_right(md5(if(lower(some_environment_column) in ('dxt', 'daily', 'msit', 'prod'), lower(some_environment_column), 'n/a')), 16), 16, -10), some_really_long_default_value_here) as result, x from t",
+      expected:
+        "SELECT\n     COALESCE(\n         CONV(util.pad_right(\n                 MD5(IF(\n                     LOWER(some_environment_column) IN ('dxt', 'daily', 'msit', 'prod')\n                    ,LOWER(some_environment_column)\n                    ,'n/a'\n                ))\n                ,16\n            )\n            ,16\n            ,-10\n        )\n        ,some_really_long_default_value_here\n    ) AS result\n    ,x\nFROM t",
+      idempotent: true,
+    },
     {
       name: 'Short NVL2 stays inline (under 140 chars)',
       input: 'select nvl2(flag, upper(yes_val), lower(no_val)), x from t',
@@ -200,6 +376,46 @@ export const arrayAccessTests: TestSuite = {
       input: 'select arr[i + 1], b from t',
       expected: 'SELECT\n     arr[i + 1]\n    ,b\nFROM t',
     },
+    {
+      name: 'Index expression spacing normalized, none inside brackets',
+      input: 'select arr[ i+1 ], arr[-1], b from t',
+      expected: 'SELECT\n     arr[i + 1]\n    ,arr[-1]\n    ,b\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Map access with concatenated key',
+      input: "select m['key'||suffix], b from t",
+      expected: "SELECT\n     m['key' || suffix]\n    ,b\nFROM t",
+      idempotent: true,
+    },
+  ],
+};
+
+export const structConstructorTests: TestSuite = {
+  name: 'STRUCT and NAMED_STRUCT Constructors',
+  tests: [
+    {
+      name: 'STRUCT with named fields uppercases AS',
+      input: 'select struct(a as FirstName, b as y) as s, c from t',
+      expected:
+        'SELECT\n     STRUCT(a AS FirstName, b AS y) AS s\n    ,c\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'NAMED_STRUCT stays inline when short',
+      input: "select named_struct('x', a, 'y', b) as s, c from t",
+      expected:
+        "SELECT\n     NAMED_STRUCT('x', a, 'y', b) AS s\n    ,c\nFROM t",
+      idempotent: true,
+    },
+    {
+      name: 'Long NAMED_STRUCT wraps one argument per line',
+      input:
+        "select named_struct('customer_identifier', customer_id, 'customer_full_name', full_name, 'email', email) as s, c from t",
+      expected:
+        "SELECT\n     NAMED_STRUCT(\n         'customer_identifier'\n        ,customer_id\n        ,'customer_full_name'\n        ,full_name\n        ,'email'\n        ,email\n    ) AS s\n    ,c\nFROM t",
+      idempotent: true,
+    },
   ],
 };
 
@@ -228,5 +444,97 @@ export const lambdaTests: TestSuite = {
       expected:
         'SELECT AGGREGATE(arr, 0, (acc, x) -> acc + x, acc -> acc * 10) FROM t',
     },
+    {
+      name: 'Three nested higher-order functions stay inline when they fit',
+      input:
+        'select aggregate(transform(filter(arr, x -> x > 0), y -> y * 2), 0, (acc, z) -> acc + z) from t',
+      expected:
+        'SELECT AGGREGATE(TRANSFORM(FILTER(arr, x -> x > 0), y -> y * 2), 0, (acc, z) -> acc + z) FROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Three nested higher-order functions wrap the outer call when too long',
+      input:
+        'select aggregate(transform(filter(very_long_array_column_name, element_value -> element_value > 0), doubled_value -> doubled_value * 2), 0, (accumulator, item) -> accumulator + item), x from t',
+      expected:
+        'SELECT\n     AGGREGATE(\n         TRANSFORM(FILTER(very_long_array_column_name, element_value -> element_value > 0), doubled_value -> doubled_value * 2)\n        ,0\n        ,(accumulator, item) -> accumulator + item\n    )\n    ,x\nFROM t',
+      idempotent: true,
+    },
+  ],
+};
+
+export const namedArgumentTests: TestSuite = {
+  name: 'Named Function Arguments (=>)',
+  tests: [
+    {
+      name: 'Named argument spacing normalized',
+      input: 'select my_udf(x=>2) from t',
+      expected: 'SELECT my_udf(x => 2) FROM t',
+    },
+    {
+      name: 'Positional and named arguments mixed',
+      input: 'select func(1, x => 2) from t',
+      expected: 'SELECT func(1, x => 2) FROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Named argument on built-in function preserves key casing',
+      input: "select mask(c, upperChar => 'X') from t",
+      expected: "SELECT MASK(c, upperChar => 'X') FROM t",
+    },
+    {
+      name: 'Named argument alongside lambda',
+      input: 'select my_udf(arr, fn => transform(arr, x -> x + 1)) from t',
+      expected: 'SELECT my_udf(arr, fn => TRANSFORM(arr, x -> x + 1)) FROM t',
+    },
+  ],
+};
+
+export const parameterMarkerTests: TestSuite = {
+  name: 'Parameter Markers (? and :name)',
+  tests: [
+    {
+      name: 'Positional parameter marker',
+      input: 'select a, b from t where a = ?',
+      expected: 'SELECT\n     a\n    ,b\nFROM t\nWHERE a = ?',
+    },
+    {
+      name: 'Named parameter marker',
+      input: 'select a, b from t where a = :id',
+      expected: 'SELECT\n     a\n    ,b\nFROM t\nWHERE a = :id',
+    },
+    {
+      name: 'Mixed parameter markers alongside a :: cast',
+      input: 'select a, b from t where a = ? and b > :MinDate and c::int = 1',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t\nWHERE\n    a = ?\n    AND b > :MinDate\n    AND c::INT = 1',
+      idempotent: true,
+    },
+    {
+      name: 'Positional parameter markers in IN list',
+      input: 'select * from t where a in (?, ?, ?)',
+      expected: 'SELECT * FROM t WHERE a IN (?, ?, ?)',
+    },
+  ],
+};
+
+// Template literals below keep ${...} out of plain string literals
+const sparkVar = (name: string) => `\${${name}}`;
+
+export const variableSubstitutionTests: TestSuite = {
+  name: 'Variable Substitution',
+  tests: [
+    {
+      name: 'Variable in WHERE value position',
+      input: `select a, b from t where a = ${sparkVar('min')}`,
+      expected: `SELECT\n     a\n    ,b\nFROM t\nWHERE a = ${sparkVar('min')}`,
+      idempotent: true,
+    },
+    {
+      name: 'Variable as table name',
+      input: `select a, b from ${sparkVar('tbl')} where x = 1`,
+      expected: `SELECT\n     a\n    ,b\nFROM ${sparkVar('tbl')}\nWHERE x = 1`,
+      idempotent: true,
+    },
   ],
 };
@@ -0,0 +1,42 @@
+/**
+ * Checked Formatting Tests
+ *
+ * Tests for formatSqlChecked, which returns the formatted output together
+ * with whether it differs from the input. This suite runs formatSqlChecked
+ * instead of formatSql; each case compares the JSON-encoded result, with
+ * needsFormatting alongside so the two must agree.
+ */
+import type { TestSuite } from '../framework.js';
+
+export const formatCheckedTests: TestSuite = {
+  name: 'Checked Formatting',
+  tests: [
+    {
+      name: 'Already formatted input is unchanged',
+      input: 'SELECT\n     a\n    ,b\nFROM t',
+      expected: JSON.stringify({
+        formatted: 'SELECT\n     a\n    ,b\nFROM t',
+        changed: false,
+        needsFormatting: false,
+      }),
+    },
+    {
+      name: 'Unformatted input is changed',
+      input: 'select a, b from t',
+      expected: JSON.stringify({
+        formatted: 'SELECT\n     a\n    ,b\nFROM t',
+        changed: true,
+        needsFormatting: true,
+      }),
+    },
+    {
+      name: 'Empty input is unchanged',
+      input: '',
+      expected: JSON.stringify({
+        formatted: '',
+        changed: false,
+        needsFormatting: false,
+      }),
+    },
+  ],
+};
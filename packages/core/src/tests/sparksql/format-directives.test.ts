@@ -3,6 +3,7 @@
  *
  * Tests for formatting suppression via format directives:
  * - Statement-level: `-- fmt: off` bypasses all formatting
+ *   (until `-- fmt: on` when one follows)
  * - Line-level: `-- fmt: collapse` forces inline formatting
  */
 import type { TestSuite } from '../framework.js';
@@ -56,6 +57,24 @@ export const fmtOffTests: TestSuite = {
       input: 'select a from t1; -- fmt: off\nselect * from t2',
       expected: 'SELECT a FROM t1;\n\n-- fmt: off\nselect * from t2',
     },
+    {
+      name: 'fmt:on ends a region spanning several statements',
+      input:
+        'select a,b from t0;\n-- fmt: off\nselect   x,y from   t1;\nselect  z from t2;\n-- fmt: on\nselect c,d from t3',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t0;\n\n-- fmt: off\nselect   x,y from   t1;\n\nselect  z from t2;\n\n-- fmt: on\nSELECT\n     c\n    ,d\nFROM t3',
+    },
+    {
+      name: 'fmt:on block comment ends the region',
+      input: '/* fmt: off */ select   1;\nselect   2;\n/* fmt: on */ select 3',
+      expected:
+        '/* fmt: off */ select   1;\n\nselect   2;\n\n/* fmt: on */ SELECT 3',
+    },
+    {
+      name: 'fmt:on without a preceding fmt:off has no effect',
+      input: '-- fmt: on\nselect a,b from t',
+      expected: '-- fmt: on\nSELECT\n     a\n    ,b\nFROM t',
+    },
   ],
 };
 
@@ -0,0 +1,65 @@
+/**
+ * Range Formatting Tests
+ *
+ * Tests for formatSqlRange, which reformats only the statements overlapping
+ * a character range (editor "format selection").
+ */
+import { formatSqlRange } from '../../formatters/sparksql/index.js';
+import type { TestSuite } from '../framework.js';
+
+/**
+ * Run formatSqlRange over the range marked in the input: « starts the range
+ * and » ends it. Both markers are removed before formatting.
+ */
+export function formatMarkedRange(input: string): string {
+  const start = input.indexOf('«');
+  const end = input.indexOf('»') - 1;
+  return formatSqlRange(input.replace('«', '').replace('»', ''), start, end);
+}
+
+export const formatRangeTests: TestSuite = {
+  name: 'Range Formatting',
+  tests: [
+    {
+      name: 'Formats second statement, leaves first untouched',
+      input: 'select a, b from t1;\n«select a, b from t2»',
+      expected: 'select a, b from t1;\nSELECT\n     a\n    ,b\nFROM t2',
+    },
+    {
+      name: 'Formats first statement, leaves second untouched',
+      input: '«selec»t a, b from t1;\nselect a, b from t2',
+      expected: 'SELECT\n     a\n    ,b\nFROM t1;\nselect a, b from t2',
+    },
+    {
+      name: 'Partial overlap formats the whole statement',
+      input: 'select a, b from t1;\nselect a,« b» from t2',
+      expected: 'select a, b from t1;\nSELECT\n     a\n    ,b\nFROM t2',
+    },
+    {
+      name: 'Range spanning both statements formats both',
+      input: 'select a, «b from t1;\nselect a,» b from t2',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t1;\nSELECT\n     a\n    ,b\nFROM t2',
+    },
+    {
+      name: 'Empty range formats the statement containing the cursor',
+      input: 'select a, b from t1;\nsele«»ct a, b from t2',
+      expected: 'select a, b from t1;\nSELECT\n     a\n    ,b\nFROM t2',
+    },
+    {
+      name: 'Range over whitespace only leaves input unchanged',
+      input: 'select 1; «  »\n\nselect 2',
+      expected: 'select 1;   \n\nselect 2',
+    },
+    {
+      name: 'fmt:off statement in range is left unchanged',
+      input: '«-- fm»t: off\nselect   1;\nselect 2',
+      expected: '-- fmt: off\nselect   1;\nselect 2',
+    },
+    {
+      name: 'Statement inside a fmt:off region is left unchanged',
+      input: '-- fmt: off\nselect   1;\n«s»elect   2;\n-- fmt: on\nselect 3',
+      expected: '-- fmt: off\nselect   1;\nselect   2;\n-- fmt: on\nselect 3',
+    },
+  ],
+};
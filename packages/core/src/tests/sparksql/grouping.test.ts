@@ -17,6 +17,13 @@ export const groupByTests: TestSuite = {
       input: 'select count(*) from t group by x, y, z',
       expected: 'SELECT COUNT(*)\nFROM t\nGROUP BY\n     x\n    ,y\n    ,z',
     },
+    {
+      name: 'Unsorted select list, GROUP BY, and ORDER BY keep their order',
+      input:
+        'select c, a, b, count(*) from t group by a, c, b order by b desc, c, a',
+      expected:
+        'SELECT\n     c\n    ,a\n    ,b\n    ,COUNT(*)\nFROM t\nGROUP BY\n     a\n    ,c\n    ,b\nORDER BY\n     b DESC\n    ,c\n    ,a',
+    },
     {
       name: 'Single-item ORDER BY (inline)',
       input: 'select a, b from t order by x',
@@ -28,6 +35,20 @@ export const groupByTests: TestSuite = {
       expected:
         'SELECT\n     a\n    ,b\nFROM t\nORDER BY\n     x\n    ,y DESC\n    ,z',
     },
+    {
+      name: 'ORDER BY with mixed expressions, ordinals, and null ordering',
+      input:
+        'select a, b from t order by a desc nulls last, 2, upper(b), c asc, d + e desc, f nulls first',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t\nORDER BY\n     a DESC NULLS LAST\n    ,2\n    ,UPPER(b)\n    ,c ASC\n    ,d + e DESC\n    ,f NULLS FIRST',
+      idempotent: true,
+    },
+    {
+      name: 'Short two-item ORDER BY still goes one per line',
+      input: 'select a, b from t order by 1 desc, 2',
+      expected: 'SELECT\n     a\n    ,b\nFROM t\nORDER BY\n     1 DESC\n    ,2',
+      idempotent: true,
+    },
     {
       name: 'GROUP BY and ORDER BY',
       input:
@@ -61,6 +82,42 @@ export const groupByTests: TestSuite = {
       expected:
         'SELECT\n     a\n    ,SUM(x)\nFROM t\nGROUP BY GROUPING SETS ((a), (b), ())',
     },
+    {
+      name: 'GROUPING SETS with multi-column sets stays inline',
+      input:
+        'select a, b, sum(x) from t group by grouping sets ((a, b), (a), ())',
+      expected:
+        'SELECT\n     a\n    ,b\n    ,SUM(x)\nFROM t\nGROUP BY GROUPING SETS ((a, b), (a), ())',
+    },
+    {
+      name: 'GROUPING and GROUPING_ID functions',
+      input:
+        'select a, b, grouping(a), grouping_id(a, b), sum(x) from t group by grouping sets ((a, b), (a), ())',
+      expected:
+        'SELECT\n     a\n    ,b\n    ,GROUPING(a)\n    ,GROUPING_ID(a, b)\n    ,SUM(x)\nFROM t\nGROUP BY GROUPING SETS ((a, b), (a), ())',
+      idempotent: true,
+    },
+    {
+      // Spark spells the grand total as an empty grouping set
+      name: 'Grand total via GROUPING SETS (())',
+      input: 'select sum(x) from t group by grouping sets (())',
+      expected: 'SELECT SUM(x)\nFROM t\nGROUP BY GROUPING SETS (())',
+      idempotent: true,
+    },
+    {
+      // Bare GROUP BY () is not in the Spark grammar; the recovered parse
+      // must still keep the empty parens rather than drop them
+      name: 'GROUP BY () is not dropped',
+      input: 'select sum(x) from t group by ()',
+      expected: 'SELECT SUM(x)\nFROM t\nGROUP BY ()',
+    },
+    {
+      name: 'GROUPING_ID with ROLLUP',
+      input:
+        'select a, b, grouping_id() as gid, sum(x) from t group by rollup(a, b)',
+      expected:
+        'SELECT\n     a\n    ,b\n    ,GROUPING_ID() AS gid\n    ,SUM(x)\nFROM t\nGROUP BY ROLLUP(a, b)',
+    },
     {
       name: 'ROLLUP inline',
       input: 'select a, b, sum(x) from t group by rollup(a, b)',
@@ -84,6 +141,13 @@ export const groupByTests: TestSuite = {
       expected:
         'SELECT\n     col1\n    ,SUM(col2)\nFROM t1\nWHERE col3 > 10\nGROUP BY ALL',
     },
+    {
+      name: 'ORDER BY with COLLATE keeps collation name',
+      input: 'select a, b from t order by a collate unicode_ci desc',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t\nORDER BY a COLLATE unicode_ci DESC',
+      idempotent: true,
+    },
   ],
 };
 
@@ -93,7 +157,7 @@ export const distributionTests: TestSuite = {
     {
       name: 'CLUSTER BY',
       input: 'select a, b from t cluster by a',
-      expected: 'SELECT\n     a\n    ,b\nFROM t CLUSTER BY a',
+      expected: 'SELECT\n     a\n    ,b\nFROM t\nCLUSTER BY a',
     },
     {
       name: 'CLUSTER BY single column stays inline',
@@ -103,7 +167,7 @@ export const distributionTests: TestSuite = {
     {
       name: 'DISTRIBUTE BY SORT BY',
       input: 'select a, b from t distribute by a sort by b',
-      expected: 'SELECT\n     a\n    ,b\nFROM t DISTRIBUTE BY a SORT BY b',
+      expected: 'SELECT\n     a\n    ,b\nFROM t\nDISTRIBUTE BY a\nSORT BY b',
     },
     {
       name: 'DISTRIBUTE BY single column stays inline',
@@ -115,5 +179,49 @@ export const distributionTests: TestSuite = {
       input: 'select * from t sort by x',
       expected: 'SELECT * FROM t SORT BY x',
     },
+    {
+      name: 'CLUSTER BY multiple columns',
+      input: 'select a, b from t cluster by a, b',
+      expected: 'SELECT\n     a\n    ,b\nFROM t\nCLUSTER BY\n     a\n    ,b',
+    },
+    {
+      name: 'DISTRIBUTE BY and SORT BY multiple columns',
+      input: 'select a, b, c from t distribute by a, b sort by c, a desc',
+      expected:
+        'SELECT\n     a\n    ,b\n    ,c\nFROM t\nDISTRIBUTE BY\n     a\n    ,b\nSORT BY\n     c\n    ,a DESC',
+    },
+    {
+      name: 'SORT BY keeps per-item direction and null ordering',
+      input:
+        'select a, b from t distribute by a sort by a desc nulls last, b asc',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t\nDISTRIBUTE BY a\nSORT BY\n     a DESC NULLS LAST\n    ,b ASC',
+      idempotent: true,
+    },
+    {
+      name: 'CLUSTER BY after WHERE and before LIMIT',
+      input: 'select a, b from t where x > 0 cluster by a limit 10',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t\nWHERE x > 0\nCLUSTER BY a\nLIMIT 10',
+    },
+    {
+      name: 'SORT BY after GROUP BY',
+      input: 'select a, count(*) from t group by a sort by a',
+      expected: 'SELECT\n     a\n    ,COUNT(*)\nFROM t\nGROUP BY a\nSORT BY a',
+    },
+    {
+      name: 'Multi-column DISTRIBUTE BY with single-column SORT BY',
+      input: 'select a, b from t distribute by a, b sort by b',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t\nDISTRIBUTE BY\n     a\n    ,b\nSORT BY b',
+      idempotent: true,
+    },
+    {
+      name: 'WINDOW clause commas do not count toward SORT BY',
+      input: 'select a, b from t sort by a window w as (partition by x, y)',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t\nSORT BY a WINDOW w AS (PARTITION BY x, y)',
+      idempotent: true,
+    },
   ],
 };
@@ -18,6 +18,31 @@ export const joinTests: TestSuite = {
       input: 'select * from a left join b on a.id = b.id',
       expected: 'SELECT *\nFROM a\nLEFT JOIN b\n    ON a.id = b.id',
     },
+    {
+      name: 'LEFT OUTER JOIN preserved',
+      input: 'select * from a left outer join b on a.id = b.id',
+      expected: 'SELECT *\nFROM a\nLEFT OUTER JOIN b\n    ON a.id = b.id',
+    },
+    {
+      name: 'RIGHT JOIN',
+      input: 'select * from a right join b on a.id = b.id',
+      expected: 'SELECT *\nFROM a\nRIGHT JOIN b\n    ON a.id = b.id',
+    },
+    {
+      name: 'RIGHT OUTER JOIN preserved',
+      input: 'select * from a right outer join b on a.id = b.id',
+      expected: 'SELECT *\nFROM a\nRIGHT OUTER JOIN b\n    ON a.id = b.id',
+    },
+    {
+      name: 'FULL JOIN',
+      input: 'select * from a full join b on a.id = b.id',
+      expected: 'SELECT *\nFROM a\nFULL JOIN b\n    ON a.id = b.id',
+    },
+    {
+      name: 'FULL OUTER JOIN preserved',
+      input: 'select * from a full outer join b on a.id = b.id',
+      expected: 'SELECT *\nFROM a\nFULL OUTER JOIN b\n    ON a.id = b.id',
+    },
     {
       name: 'LEFT SEMI JOIN',
       input: 'select * from t1 left semi join t2 on t1.id = t2.id',
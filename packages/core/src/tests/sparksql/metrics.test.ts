@@ -0,0 +1,90 @@
+/**
+ * Query Metrics Tests
+ *
+ * Tests for the public analyzeSql() complexity report. This suite runs
+ * analyzeSql instead of formatSql; each case compares the JSON-encoded
+ * metrics against the expected value.
+ */
+import type { TestSuite } from '../framework.js';
+
+// Template literals below keep ${...} out of plain string literals
+const sparkVar = (name: string) => `\${${name}}`;
+
+export const queryMetricsTests: TestSuite = {
+  name: 'Query Metrics',
+  tests: [
+    {
+      name: 'Two joins and one CTE',
+      input:
+        'WITH c AS (SELECT id FROM a) SELECT c.id, b.x, d.y FROM c ' +
+        'JOIN b ON c.id = b.id LEFT JOIN d ON d.id = b.id',
+      expected: JSON.stringify({
+        joins: 2,
+        subqueries: 0,
+        ctes: 1,
+        maxNestingDepth: 1,
+        selectStar: false,
+      }),
+    },
+    {
+      name: 'SELECT * is flagged',
+      input: 'SELECT * FROM t',
+      expected: JSON.stringify({
+        joins: 0,
+        subqueries: 0,
+        ctes: 0,
+        maxNestingDepth: 0,
+        selectStar: true,
+      }),
+    },
+    {
+      name: 'COUNT(*) is not SELECT *',
+      input: 'SELECT COUNT(*) FROM t',
+      expected: JSON.stringify({
+        joins: 0,
+        subqueries: 0,
+        ctes: 0,
+        maxNestingDepth: 0,
+        selectStar: false,
+      }),
+    },
+    {
+      name: 'Nested subqueries report depth',
+      input:
+        'SELECT a FROM t WHERE a IN (SELECT b FROM u ' +
+        'WHERE EXISTS (SELECT 1 FROM v))',
+      expected: JSON.stringify({
+        joins: 0,
+        subqueries: 2,
+        ctes: 0,
+        maxNestingDepth: 2,
+        selectStar: false,
+      }),
+    },
+    {
+      name: 'Variable substitution as a table name',
+      input: `SELECT a FROM ${sparkVar('src')} s JOIN u ON s.id = u.id`,
+      expected: JSON.stringify({
+        joins: 1,
+        subqueries: 0,
+        ctes: 0,
+        maxNestingDepth: 0,
+        selectStar: false,
+      }),
+    },
+    {
+      // Error recovery would count the broken JOIN and its star
+      name: 'Statement with a syntax error is skipped',
+      input:
+        'SELECT a FROM t JOIN u ON t.id = u.id; ' +
+        'SELECT * FROM b JOIN c ON; SELECT x FROM v',
+      expected: JSON.stringify({
+        joins: 1,
+        subqueries: 0,
+        ctes: 0,
+        maxNestingDepth: 0,
+        selectStar: false,
+      }),
+    },
+  ],
+};
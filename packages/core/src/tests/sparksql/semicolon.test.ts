@@ -59,5 +59,103 @@ export const semicolonTests: TestSuite = {
       input: '; select 1',
       expected: 'SELECT 1',
     },
+    // Trailing blank lines at end of input
+    {
+      name: 'Trailing blank lines are removed',
+      input: 'select a, b from t\n\n\n',
+      expected: 'SELECT\n     a\n    ,b\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Trailing blank lines after final semicolon are removed',
+      input: 'select a, b from t;\n\n\n',
+      expected: 'SELECT\n     a\n    ,b\nFROM t;',
+    },
+    {
+      name: 'Trailing blank lines after a line comment are removed',
+      input: 'select x, y from t -- done\n\n\n',
+      expected: 'SELECT\n     x\n    ,y\nFROM t -- done',
+    },
+    {
+      name: 'Blank lines between statements collapse to one',
+      input: 'select 1;\n\n\n\nselect 2',
+      expected: 'SELECT 1;\n\nSELECT 2',
+    },
+    {
+      // Each statement is parsed on its own, so one that fails to parse
+      // neither blocks the others nor is dropped from the output. Its
+      // unknown words are plain identifiers and keep their casing.
+      name: 'Invalid statement does not affect its neighbours',
+      input: 'select 1; selec x frm y; select a, b from t',
+      expected: 'SELECT 1;\n\nselec x frm y;\n\nSELECT\n     a\n    ,b\nFROM t',
+    },
+  ],
+};
+
+export const emptyInputTests: TestSuite = {
+  name: 'Empty and Whitespace-Only Input',
+  tests: [
+    {
+      name: 'Empty string',
+      input: '',
+      expected: '',
+    },
+    {
+      name: 'Whitespace only',
+      input: '   \n',
+      expected: '',
+    },
+    {
+      // Semicolon-terminated input keeps its terminator even when every
+      // statement is empty, matching ';;;' above; only whitespace-only input
+      // formats to ''
+      name: 'Whitespace around lone semicolon',
+      input: '  ;  \n',
+      expected: ';',
+    },
+    {
+      name: 'Lone header comment',
+      input: '-- header\n',
+      expected: '-- header',
+      idempotent: true,
+    },
+  ],
+};
+
+/**
+ * Statement span tests run getStatementSpans instead of formatSql; each
+ * case compares the JSON-encoded spans against the expected value.
+ */
+export const statementSpanTests: TestSuite = {
+  name: 'Statement Spans',
+  tests: [
+    {
+      name: 'Single statement covers whole input',
+      input: 'SELECT a FROM t',
+      expected: JSON.stringify([
+        { start: 0, end: 15, text: 'SELECT a FROM t' },
+      ]),
+    },
+    {
+      name: 'Spans exclude whitespace and semicolons',
+      input: '  select 1 ;\n\nselect 2;',
+      expected: JSON.stringify([
+        { start: 2, end: 10, text: 'select 1' },
+        { start: 14, end: 22, text: 'select 2' },
+      ]),
+    },
+    {
+      name: 'Semicolon inside string literal does not split',
+      input: "select 'a;b'; select 2",
+      expected: JSON.stringify([
+        { start: 0, end: 12, text: "select 'a;b'" },
+        { start: 14, end: 22, text: 'select 2' },
+      ]),
+    },
+    {
+      name: 'Offsets account for %%sql magic command',
+      input: '%%sql\nselect 1',
+      expected: JSON.stringify([{ start: 6, end: 14, text: 'select 1' }]),
+    },
   ],
 };
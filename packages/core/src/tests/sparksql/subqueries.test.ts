@@ -21,6 +21,20 @@ export const subqueryTests: TestSuite = {
       expected:
         'SELECT\n     a\n    ,b\nFROM (\n    SELECT\n         a\n        ,b\n    FROM (\n        SELECT\n             a\n            ,b\n        FROM t\n    ) inner_q\n) outer_q',
     },
+    {
+      name: 'Qualified star from subquery alias',
+      input: 'select x.*, 1 as n from (select a, b from t) x',
+      expected:
+        'SELECT\n     x.*\n    ,1 AS n\nFROM (\n    SELECT\n         a\n        ,b\n    FROM t\n) x',
+      idempotent: true,
+    },
+    {
+      name: 'Qualified columns from subquery alias',
+      input: 'select x.a, x.b from (select a, b from t) x where x.a > 0',
+      expected:
+        'SELECT\n     x.a\n    ,x.b\nFROM (\n    SELECT\n         a\n        ,b\n    FROM t\n) x\nWHERE x.a > 0',
+      idempotent: true,
+    },
     {
       name: 'WHERE IN subquery',
       input:
@@ -35,6 +49,22 @@ export const subqueryTests: TestSuite = {
       expected:
         'SELECT\n     a\n    ,b\nFROM t\nWHERE EXISTS (\n    SELECT 1\n    FROM other\n    WHERE\n        other.id = t.id\n        AND other.status = 1\n)',
     },
+    {
+      name: 'Correlated EXISTS with simple subquery stays inline',
+      input:
+        'select a, b from t where exists (select 1 from u where u.id = t.id)',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t\nWHERE EXISTS (SELECT 1 FROM u WHERE u.id = t.id)',
+      idempotent: true,
+    },
+    {
+      name: 'Correlated NOT EXISTS inside a condition chain',
+      input:
+        'select a, b from t where t.active = 1 and not exists (select 1 from u where u.id = t.id)',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t\nWHERE\n    t.active = 1\n    AND NOT EXISTS (SELECT 1 FROM u WHERE u.id = t.id)',
+      idempotent: true,
+    },
   ],
 };
 
@@ -54,6 +84,41 @@ export const cteTests: TestSuite = {
       expected:
         'WITH cte1 AS (\n    SELECT\n         a\n        ,b\n    FROM t1\n)\n,cte2 AS (\n    SELECT\n         c\n        ,d\n    FROM t2\n)\nSELECT\n     a\n    ,c\nFROM cte1\nJOIN cte2\n    ON cte1.a = cte2.c',
     },
+    {
+      name: 'CTE tight AS( gets a space',
+      input: 'with cte as(select a, b from t) select a, b from cte',
+      expected:
+        'WITH cte AS (\n    SELECT\n         a\n        ,b\n    FROM t\n)\nSELECT\n     a\n    ,b\nFROM cte',
+    },
+    {
+      name: 'CTE extra spaces around AS collapse',
+      input: 'WITH cte   As   (select a, b from t) select a, b from cte',
+      expected:
+        'WITH cte AS (\n    SELECT\n         a\n        ,b\n    FROM t\n)\nSELECT\n     a\n    ,b\nFROM cte',
+      idempotent: true,
+    },
+    {
+      name: 'CTE with column list',
+      input: 'with cte (x, y) as(select a, b from t) select x, y from cte',
+      expected:
+        'WITH cte (x, y) AS (\n    SELECT\n         a\n        ,b\n    FROM t\n)\nSELECT\n     x\n    ,y\nFROM cte',
+    },
+    {
+      name: 'WITH inside a FROM subquery',
+      input:
+        'select a, b from (with c as (select a, b from t) select a, b from c) x',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM (\n    WITH c AS (\n        SELECT\n             a\n            ,b\n        FROM t\n    )\n    SELECT\n         a\n        ,b\n    FROM c\n) x',
+      idempotent: true,
+    },
+    {
+      name: 'WITH inside a CTE body',
+      input:
+        'with o as (with i as (select a, b from t) select a, b from i) select a, b from o',
+      expected:
+        'WITH o AS (\n    WITH i AS (\n        SELECT\n             a\n            ,b\n        FROM t\n    )\n    SELECT\n         a\n        ,b\n    FROM i\n)\nSELECT\n     a\n    ,b\nFROM o',
+      idempotent: true,
+    },
   ],
 };
 
@@ -78,6 +143,40 @@ export const setOperationTests: TestSuite = {
       expected:
         'SELECT\n     a\n    ,b\nFROM t1\nINTERSECT\nSELECT\n     a\n    ,b\nFROM t2',
     },
+    {
+      // The quantifier is preserved as written, never added or removed
+      name: 'UNION DISTINCT keeps explicit DISTINCT',
+      input: 'select a, b from t1 union distinct select a, b from t2',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t1\nUNION DISTINCT\nSELECT\n     a\n    ,b\nFROM t2',
+    },
+    {
+      name: 'Bare UNION does not gain DISTINCT',
+      input: 'select a, b from t1 union select a, b from t2',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t1\nUNION\nSELECT\n     a\n    ,b\nFROM t2',
+    },
+    {
+      name: 'EXCEPT DISTINCT and INTERSECT DISTINCT keep explicit DISTINCT',
+      input:
+        'select a, b from t1 except distinct select a, b from t2 intersect distinct select a, b from t3',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t1\nEXCEPT DISTINCT\nSELECT\n     a\n    ,b\nFROM t2\nINTERSECT DISTINCT\nSELECT\n     a\n    ,b\nFROM t3',
+    },
+    {
+      name: 'Three-branch set operation puts each operator on its own line',
+      input:
+        'select a, b from t1 union all select a, b from t2 intersect select a, b from t3',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t1\nUNION ALL\nSELECT\n     a\n    ,b\nFROM t2\nINTERSECT\nSELECT\n     a\n    ,b\nFROM t3',
+      idempotent: true,
+    },
+    {
+      name: 'MINUS starts its own line like EXCEPT',
+      input: 'select a, b from t1 minus select a, b from t2',
+      expected:
+        'SELECT\n     a\n    ,b\nFROM t1\nMINUS\nSELECT\n     a\n    ,b\nFROM t2',
+    },
 
     // === BUG: SET OPERATIONS WITH PARENTHESES ===
     // Parenthesized queries should have proper indentation
@@ -0,0 +1,69 @@
+/**
+ * Tokenizer Tests
+ *
+ * Tests for the public tokenize() token stream. These suites run tokenize
+ * instead of formatSql; each case compares the JSON-encoded [kind, text]
+ * pairs (or [start, end] offsets) against the expected value.
+ */
+import type { TestSuite } from '../framework.js';
+
+export const tokenizerTests: TestSuite = {
+  name: 'Tokenizer',
+  tests: [
+    {
+      name: 'Keywords, identifiers, whitespace, and line comment',
+      input: 'SELECT a -- c\nFROM t',
+      expected: JSON.stringify([
+        ['keyword', 'SELECT'],
+        ['whitespace', ' '],
+        ['identifier', 'a'],
+        ['whitespace', ' '],
+        ['comment', '-- c\n'],
+        ['keyword', 'FROM'],
+        ['whitespace', ' '],
+        ['identifier', 't'],
+      ]),
+    },
+    {
+      name: 'Literals and operators',
+      input: "x = 'a' + 1",
+      expected: JSON.stringify([
+        ['identifier', 'x'],
+        ['whitespace', ' '],
+        ['operator', '='],
+        ['whitespace', ' '],
+        ['literal', "'a'"],
+        ['whitespace', ' '],
+        ['operator', '+'],
+        ['whitespace', ' '],
+        ['literal', '1'],
+      ]),
+    },
+    {
+      name: 'Keyword casing is preserved in token text',
+      input: 'select /* hi */ `My Col`',
+      expected: JSON.stringify([
+        ['keyword', 'select'],
+        ['whitespace', ' '],
+        ['comment', '/* hi */'],
+        ['whitespace', ' '],
+        ['identifier', '`My Col`'],
+      ]),
+    },
+  ],
+};
+
+export const tokenOffsetTests: TestSuite = {
+  name: 'Tokenizer Offsets',
+  tests: [
+    {
+      name: 'Token offsets cover the input',
+      input: 'SELECT a',
+      expected: JSON.stringify([
+        [0, 6],
+        [6, 7],
+        [7, 8],
+      ]),
+    },
+  ],
+};
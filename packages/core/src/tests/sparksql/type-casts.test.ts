@@ -24,6 +24,25 @@ export const castTests: TestSuite = {
       input: 'select try_cast(x as int) from t',
       expected: 'SELECT TRY_CAST(x AS INT) FROM t',
     },
+    {
+      name: 'TRY_CAST and CAST stay distinct side by side',
+      input:
+        'select try_cast(a as int), cast(b as int), Try_Cast(c as date) from t',
+      expected:
+        'SELECT\n     TRY_CAST(a AS INT)\n    ,CAST(b AS INT)\n    ,TRY_CAST(c AS DATE)\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'CAST to parameterized type normalizes spacing',
+      input: 'select cast( a as decimal(10,2) ), b from t',
+      expected: 'SELECT\n     CAST(a AS DECIMAL(10, 2))\n    ,b\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'CAST collapses extra whitespace around AS',
+      input: 'select cast(a   as   varchar(20)) from t',
+      expected: 'SELECT CAST(a AS VARCHAR(20)) FROM t',
+    },
   ],
 };
 
@@ -50,5 +69,43 @@ export const doubleColonCastTests: TestSuite = {
       input: 'select x::struct<a:int, b:string> from t',
       expected: 'SELECT x::STRUCT<a:INT, b:STRING> FROM t',
     },
+    {
+      name: 'Double-colon cast to parameterized type',
+      input: 'select a::decimal(10,2) from t',
+      expected: 'SELECT a::DECIMAL(10, 2) FROM t',
+    },
+    {
+      name: 'Double-colon cast after field access',
+      input: 'select t.a.b::int, c from t',
+      expected: 'SELECT\n     t.a.b::INT\n    ,c\nFROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Field access after a double-colon struct cast',
+      input: 'select x::struct<a:int>.a from t',
+      expected: 'SELECT x::STRUCT<a:INT>.a FROM t',
+      idempotent: true,
+    },
+    {
+      name: 'Field access on CAST and struct-returning function results',
+      input: "select cast(a as struct<x:int>).x, named_struct('y', 1).y from t",
+      expected:
+        "SELECT\n     CAST(a AS STRUCT<x:INT>).x\n    ,NAMED_STRUCT('y', 1).y\nFROM t",
+      idempotent: true,
+    },
+    {
+      name: 'Chained :: casts',
+      input: 'select x::string::int, y from t',
+      expected: 'SELECT\n     x::STRING::INT\n    ,y\nFROM t',
+      idempotent: true,
+    },
+    {
+      // Spark has no AT TIME ZONE; FROM_UTC_TIMESTAMP is the equivalent
+      name: ':: cast inside a time zone conversion',
+      input: "select from_utc_timestamp(ts::timestamp, 'UTC'), y from t",
+      expected:
+        "SELECT\n     FROM_UTC_TIMESTAMP(ts::TIMESTAMP, 'UTC')\n    ,y\nFROM t",
+      idempotent: true,
+    },
   ],
 };
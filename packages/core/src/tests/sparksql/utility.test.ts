@@ -11,6 +11,24 @@ export const utilityTests: TestSuite = {
       input: 'use my_database',
       expected: 'USE my_database',
     },
+    {
+      name: 'USE qualified name',
+      input: 'use spark_catalog.sales',
+      expected: 'USE spark_catalog.sales',
+      idempotent: true,
+    },
+    {
+      name: 'USE SCHEMA keeps namespace keyword',
+      input: 'use schema sales',
+      expected: 'USE SCHEMA sales',
+      idempotent: true,
+    },
+    {
+      name: 'USE DATABASE with backticked name',
+      input: 'Use Database `my-db`',
+      expected: 'USE DATABASE `my-db`',
+      idempotent: true,
+    },
     {
       name: 'SHOW TABLES',
       input: 'show tables',
@@ -56,11 +74,36 @@ export const utilityTests: TestSuite = {
       input: 'set spark.sql.shuffle.partitions = 200',
       expected: 'SET spark.sql.shuffle.partitions = 200',
     },
+    {
+      name: 'SET config normalizes spacing around equals',
+      input: 'SET   spark.sql.ansi.enabled=true',
+      expected: 'SET spark.sql.ansi.enabled = true',
+      idempotent: true,
+    },
+    {
+      name: 'SET config key without value',
+      input: 'set spark.sql.shuffle.partitions',
+      expected: 'SET spark.sql.shuffle.partitions',
+    },
+    {
+      name: 'SET config mixed with queries',
+      input:
+        'set spark.sql.shuffle.partitions = 200; select a, b from t; set spark.sql.ansi.enabled',
+      expected:
+        'SET spark.sql.shuffle.partitions = 200;\n\nSELECT\n     a\n    ,b\nFROM t;\n\nSET spark.sql.ansi.enabled',
+      idempotent: true,
+    },
     // TRANSFORM (Hive streaming)
     {
       name: 'TRANSFORM (Hive streaming)',
       input: "select transform(a, b) using 'script.py' as (c, d) from t",
       expected: "SELECT TRANSFORM(a, b) USING 'script.py' AS (c, d)\nFROM t",
     },
+    {
+      name: 'TRANSFORM round-trips unchanged',
+      input: "SELECT TRANSFORM(a, b) USING 'script.py' AS (c, d)\nFROM t",
+      expected: "SELECT TRANSFORM(a, b) USING 'script.py' AS (c, d)\nFROM t",
+      idempotent: true,
+    },
   ],
 };
@@ -24,6 +24,18 @@ export const whereTests: TestSuite = {
       expected:
         'SELECT\n     a\n    ,b\nFROM t\nWHERE\n    a = 1\n    OR b = 2\n    OR c = 3',
     },
+    // Boolean chains break on every AND/OR regardless of width or count
+    {
+      name: 'Short two-predicate WHERE still breaks',
+      input: 'select * from t where a = 1 and b = 2',
+      expected: 'SELECT *\nFROM t\nWHERE\n    a = 1\n    AND b = 2',
+    },
+    {
+      name: 'Four-predicate WHERE breaks one per line',
+      input: 'select * from t where a = 1 and b = 2 and c = 3 and d = 4',
+      expected:
+        'SELECT *\nFROM t\nWHERE\n    a = 1\n    AND b = 2\n    AND c = 3\n    AND d = 4',
+    },
     {
       name: 'IS NULL',
       input: 'select x, y from t where x is null',
@@ -45,6 +57,28 @@ export const whereTests: TestSuite = {
       expected:
         'SELECT\n     x\n    ,y\nFROM t\nWHERE x IS NOT DISTINCT FROM y',
     },
+    {
+      name: 'Not-equal != preserved',
+      input: 'select x, y from t where x!=y',
+      expected: 'SELECT\n     x\n    ,y\nFROM t\nWHERE x != y',
+    },
+    {
+      name: 'Not-equal <> preserved',
+      input: 'select x, y from t where x<>y',
+      expected: 'SELECT\n     x\n    ,y\nFROM t\nWHERE x <> y',
+    },
+    {
+      name: 'Null-safe equal <=> preserved',
+      input: 'select x, y from t where x<=>y',
+      expected: 'SELECT\n     x\n    ,y\nFROM t\nWHERE x <=> y',
+    },
+    {
+      name: 'Mixed not-equal operators are not canonicalized',
+      input: 'select x, y from t where x != 1 and y <> 2 or x <=> y',
+      expected:
+        'SELECT\n     x\n    ,y\nFROM t\nWHERE\n    x != 1\n    AND y <> 2\n    OR x <=> y',
+      idempotent: true,
+    },
     {
       name: 'BETWEEN (dont split on AND)',
       input: 'select x, y from t where x between 1 and 10',
@@ -73,11 +107,51 @@ export const whereTests: TestSuite = {
       expected:
         'SELECT *\nFROM very_long_table_name\nWHERE long_column_name IN (value_one, value_two, value_three, value_four, value_five, value_six, value_seven, value_eight, value_nine,\n                           value_ten, value_eleven, value_twelve, value_thirteen)',
     },
+    {
+      name: 'Wrapped IN list is stable on reformat',
+      input:
+        'SELECT *\nFROM very_long_table_name\nWHERE long_column_name IN (value_one, value_two, value_three, value_four, value_five, value_six, value_seven, value_eight, value_nine,\n                           value_ten, value_eleven, value_twelve, value_thirteen)',
+      expected:
+        'SELECT *\nFROM very_long_table_name\nWHERE long_column_name IN (value_one, value_two, value_three, value_four, value_five, value_six, value_seven, value_eight, value_nine,\n                           value_ten, value_eleven, value_twelve, value_thirteen)',
+      idempotent: true,
+    },
+    {
+      name: 'Single-element IN list stays parenthesized',
+      input: 'select x, y from t where x in (1)',
+      expected: 'SELECT\n     x\n    ,y\nFROM t\nWHERE x IN (1)',
+      idempotent: true,
+    },
+    {
+      name: 'NOT IN list',
+      input: "select x, y from t where x not in ('a','b','c')",
+      expected:
+        "SELECT\n     x\n    ,y\nFROM t\nWHERE x NOT IN ('a', 'b', 'c')",
+      idempotent: true,
+    },
+    {
+      name: 'COLLATE in comparison keeps collation name',
+      input: "select a, b from t where name collate UTF8_LCASE = 'x'",
+      expected:
+        "SELECT\n     a\n    ,b\nFROM t\nWHERE name COLLATE UTF8_LCASE = 'x'",
+      idempotent: true,
+    },
     {
       name: 'RLIKE',
       input: 'select x, y from t where x rlike pattern',
       expected: 'SELECT\n     x\n    ,y\nFROM t\nWHERE x RLIKE pattern',
     },
+    {
+      name: 'REGEXP kept as written, not normalized to RLIKE',
+      input: "select x, y from t where x regexp '^a.*'",
+      expected: "SELECT\n     x\n    ,y\nFROM t\nWHERE x REGEXP '^a.*'",
+    },
+    {
+      name: 'NOT RLIKE preserves pattern literal verbatim',
+      input: "select x, y from t where x not rlike '\\\\d{3}-[A-z]+'",
+      expected:
+        "SELECT\n     x\n    ,y\nFROM t\nWHERE x NOT RLIKE '\\\\d{3}-[A-z]+'",
+      idempotent: true,
+    },
 
     // === BUG: LIKE ESCAPE CLAUSE ===
     // ESCAPE clause should stay with LIKE expression
@@ -94,6 +168,18 @@ export const whereTests: TestSuite = {
       expected:
         "SELECT\n     a\n    ,b\nFROM t\nWHERE\n    name LIKE '%test%' ESCAPE '!'\n    AND status = 1",
     },
+    {
+      name: 'LIKE pattern containing a newline is kept verbatim',
+      input: "select a, b from t where name   like   'a%\n%b'   escape   '!'",
+      expected:
+        "SELECT\n     a\n    ,b\nFROM t\nWHERE name LIKE 'a%\n%b' ESCAPE '!'",
+      idempotent: true,
+    },
+    {
+      name: 'ILIKE pattern containing a tab is kept verbatim',
+      input: "select a, b from t where name ilike 'a\t  %'",
+      expected: "SELECT\n     a\n    ,b\nFROM t\nWHERE name ILIKE 'a\t  %'",
+    },
 
     // LIKE with ALL/ANY/SOME quantifiers
     {
@@ -111,6 +197,30 @@ export const whereTests: TestSuite = {
       input: 'select * from t where x like some (a, b, c)',
       expected: 'SELECT * FROM t WHERE x LIKE SOME (a, b, c)',
     },
+    {
+      name: 'ILIKE',
+      input: "select x, y from t where x ilike 'a%'",
+      expected: "SELECT\n     x\n    ,y\nFROM t\nWHERE x ILIKE 'a%'",
+      idempotent: true,
+    },
+    {
+      name: 'NOT ILIKE',
+      input: "select x, y from t where x not ilike 'a%'",
+      expected: "SELECT\n     x\n    ,y\nFROM t\nWHERE x NOT ILIKE 'a%'",
+    },
+    {
+      name: 'LIKE ANY with string patterns',
+      input: "select x, y from t where x like any ('a%', 'b%')",
+      expected: "SELECT\n     x\n    ,y\nFROM t\nWHERE x LIKE ANY ('a%', 'b%')",
+      idempotent: true,
+    },
+    {
+      name: 'NOT LIKE ALL with string patterns',
+      input: "select x, y from t where x not like all ('a%', 'b%')",
+      expected:
+        "SELECT\n     x\n    ,y\nFROM t\nWHERE x NOT LIKE ALL ('a%', 'b%')",
+      idempotent: true,
+    },
 
     // === IN list with nested functions (regression test for fabric-format-9c5) ===
     {
@@ -5,7 +5,6 @@
  * frame specifications, and null handling modifiers.
  */
 
-import { formatSql } from '../../formatters/sparksql/index.js';
 import type { TestSuite } from '../framework.js';
 
 export const windowFunctionTests: TestSuite = {
@@ -30,6 +29,22 @@ export const windowFunctionTests: TestSuite = {
       expected:
         'SELECT ROW_NUMBER() OVER (\n        PARTITION BY extremely_long_column_name_one, extremely_long_column_name_two, extremely_long_column_name_three\n        ORDER BY yet_another_extremely_long_sort_column_name\n    )\nFROM t',
     },
+    {
+      name: 'Function call in PARTITION BY stays inline',
+      input:
+        "select ts, sum(x) over (partition by date_trunc('day', ts) order by ts) as s from t",
+      expected:
+        "SELECT\n     ts\n    ,SUM(x) OVER (PARTITION BY DATE_TRUNC('day', ts) ORDER BY ts) AS s\nFROM t",
+      idempotent: true,
+    },
+    {
+      name: 'Long PARTITION BY with function calls expands the window',
+      input:
+        "select row_number() over (partition by date_trunc('day', event_timestamp), upper(extremely_long_column_name_two), extremely_long_column_name_three order by event_timestamp) from t",
+      expected:
+        "SELECT ROW_NUMBER() OVER (\n        PARTITION BY DATE_TRUNC('day', event_timestamp), UPPER(extremely_long_column_name_two), extremely_long_column_name_three\n        ORDER BY event_timestamp\n    )\nFROM t",
+      idempotent: true,
+    },
     {
       name: 'Window with frame clause stays inline under 140',
       input:
@@ -37,6 +52,18 @@ export const windowFunctionTests: TestSuite = {
       expected:
         'SELECT SUM(amount) OVER (PARTITION BY customer_id ORDER BY transaction_date ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) FROM t',
     },
+    {
+      name: 'ROWS frame shorthand is preserved, not expanded to BETWEEN',
+      input: 'select sum(x) over (order by d rows 3 preceding) from t',
+      expected: 'SELECT SUM(x) OVER (ORDER BY d ROWS 3 PRECEDING) FROM t',
+      idempotent: true,
+    },
+    {
+      name: 'RANGE frame shorthand is preserved',
+      input: 'select sum(x) over (order by d range unbounded preceding) from t',
+      expected:
+        'SELECT SUM(x) OVER (ORDER BY d RANGE UNBOUNDED PRECEDING) FROM t',
+    },
     {
       name: 'Window in CTE stays inline when under 140',
       input:
@@ -52,17 +79,25 @@ export const windowFunctionTests: TestSuite = {
         "select dense_rank() over (order by regexp_extract_all(col_name, '([A-Z]+)',1),cast(regexp_extract(col_name, '([0-9]+)',1) as int)) as rank_col from t",
       expected:
         "SELECT DENSE_RANK() OVER (\n        ORDER BY REGEXP_EXTRACT_ALL(col_name, '([A-Z]+)', 1), CAST(REGEXP_EXTRACT(col_name, '([0-9]+)', 1) AS INT)\n    ) AS rank_col\nFROM t",
-      customValidator: (_input: string, expected: string, actual: string) => {
-        // Run formatting twice and verify idempotency
-        const pass2 = formatSql(actual);
-        if (pass2 !== actual) {
-          return {
-            passed: false,
-            message: `Not idempotent!\nPass 1: ${JSON.stringify(actual)}\nPass 2: ${JSON.stringify(pass2)}`,
-          };
-        }
-        return { passed: actual === expected };
-      },
+      idempotent: true,
+    },
+    {
+      name: 'Window ORDER BY and query ORDER BY share sort item formatting',
+      input:
+        'select a, row_number() over (order by a desc nulls last) as rn from t order by b asc nulls first',
+      expected:
+        'SELECT\n     a\n    ,ROW_NUMBER() OVER (ORDER BY a DESC NULLS LAST) AS rn\nFROM t\nORDER BY b ASC NULLS FIRST',
+      idempotent: true,
+    },
+    {
+      name: 'Empty window spec stays OVER ()',
+      input: 'select sum(x) over () from t',
+      expected: 'SELECT SUM(x) OVER () FROM t',
+    },
+    {
+      name: 'Empty window spec with multiple columns',
+      input: 'select a, row_number() over () as rn from t',
+      expected: 'SELECT\n     a\n    ,ROW_NUMBER() OVER () AS rn\nFROM t',
     },
   ],
 };
@@ -84,6 +119,14 @@ export const namedWindowTests: TestSuite = {
       expected:
         'SELECT SUM(x) OVER w FROM t WINDOW w AS (PARTITION BY y ORDER BY z)',
     },
+    {
+      name: 'Two functions sharing a named window',
+      input:
+        'select sum(x) over w, avg(x) over w from t window w as (partition by y order by z)',
+      expected:
+        'SELECT\n     SUM(x) OVER w\n    ,AVG(x) OVER w\nFROM t WINDOW w AS (PARTITION BY y ORDER BY z)',
+      idempotent: true,
+    },
   ],
 };
 
@@ -100,5 +143,50 @@ export const nullHandlingTests: TestSuite = {
       input: 'select last_value(x) respect nulls over (order by y) from t',
       expected: 'SELECT LAST_VALUE(x) RESPECT NULLS OVER (ORDER BY y) FROM t',
     },
+    {
+      // FIRST and LAST also accept IGNORE NULLS inside the parens
+      name: 'IGNORE NULLS inside FIRST with window',
+      input: 'select first(x ignore nulls) over (order by y) from t',
+      expected: 'SELECT FIRST(x IGNORE NULLS) OVER (ORDER BY y) FROM t',
+      idempotent: true,
+    },
+    {
+      name: 'IGNORE NULLS inside LAST aggregate',
+      input: 'select last(x ignore nulls), y from t group by y',
+      expected: 'SELECT\n     LAST(x IGNORE NULLS)\n    ,y\nFROM t\nGROUP BY y',
+      idempotent: true,
+    },
+    {
+      name: 'RESPECT NULLS after aggregate without window',
+      input: 'select last(x) respect nulls from t',
+      expected: 'SELECT LAST(x) RESPECT NULLS FROM t',
+      idempotent: true,
+    },
+  ],
+};
+
+export const aggregateSuffixTests: TestSuite = {
+  name: 'Aggregate Suffixes (DISTINCT/FILTER/OVER)',
+  tests: [
+    {
+      name: 'FILTER clause on aggregate',
+      input: 'select count(*) filter (where x > 0) from t',
+      expected: 'SELECT COUNT(*) FILTER (WHERE x > 0) FROM t',
+    },
+    {
+      name: 'DISTINCT, FILTER, and OVER keep canonical order',
+      input:
+        'select array_agg(distinct x) filter (where y > 0) over (partition by z) from t',
+      expected:
+        'SELECT ARRAY_AGG(DISTINCT x) FILTER (WHERE y > 0) OVER (PARTITION BY z) FROM t',
+      idempotent: true,
+    },
+    {
+      name: 'FILTER and OVER in multi-column select',
+      input:
+        'select a, count(distinct b) filter (where c = 1) over (partition by a) as cnt from t',
+      expected:
+        'SELECT\n     a\n    ,COUNT(DISTINCT b) FILTER (WHERE c = 1) OVER (PARTITION BY a) AS cnt\nFROM t',
+    },
   ],
 };